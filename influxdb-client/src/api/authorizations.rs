@@ -0,0 +1,274 @@
+//! Authorizations, i.e. API tokens, refer: <https://docs.influxdata.com/influxdb/v2.0/api/#tag/Authorizations>
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{api::Status, client::Client, models::InfluxError};
+
+/// An authorization as returned by the server.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Authorization {
+    pub id: String,
+    /// The API token. Only returned to users allowed to read it.
+    #[serde(default)]
+    pub token: Option<String>,
+    pub status: Status,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(rename = "orgID")]
+    pub org_id: String,
+    #[serde(default, rename = "userID")]
+    pub user_id: Option<String>,
+    pub permissions: Vec<Permission>,
+    #[serde(default)]
+    pub created_at: Option<String>,
+    #[serde(default)]
+    pub updated_at: Option<String>,
+}
+
+/// Allows an action on a resource.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Permission {
+    pub action: Action,
+    pub resource: Resource,
+}
+
+impl Permission {
+    pub fn read(resource: Resource) -> Self {
+        Permission {
+            action: Action::Read,
+            resource,
+        }
+    }
+
+    pub fn write(resource: Resource) -> Self {
+        Permission {
+            action: Action::Write,
+            resource,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Action {
+    Read,
+    Write,
+}
+
+/// Resources of a permission. Without an ID it covers all resources of the type.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Resource {
+    #[serde(rename = "type")]
+    pub kind: ResourceType,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(default, rename = "orgID", skip_serializing_if = "Option::is_none")]
+    pub org_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub org: Option<String>,
+}
+
+impl Resource {
+    /// All resources of a type, in the organization of the authorization.
+    pub fn all(kind: ResourceType) -> Self {
+        Resource {
+            kind,
+            id: None,
+            org_id: None,
+            name: None,
+            org: None,
+        }
+    }
+
+    /// A single bucket.
+    pub fn bucket(bucket_id: impl Into<String>) -> Self {
+        Resource {
+            id: Some(bucket_id.into()),
+            ..Resource::all(ResourceType::Buckets)
+        }
+    }
+
+    /// Use resources of another organization than the one of the authorization.
+    pub fn with_org_id(mut self, org_id: impl Into<String>) -> Self {
+        self.org_id = Some(org_id.into());
+        self
+    }
+}
+
+/// Type of a resource.
+///
+/// Types added by newer servers, e.g. `instance` or `notebooks`, are kept as `Other` and
+/// written back as they were read.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ResourceType {
+    Authorizations,
+    Buckets,
+    Checks,
+    Dashboards,
+    Dbrp,
+    Documents,
+    Labels,
+    NotificationEndpoints,
+    NotificationRules,
+    Orgs,
+    Scrapers,
+    Secrets,
+    Sources,
+    Tasks,
+    Telegrafs,
+    Users,
+    Variables,
+    Views,
+    #[serde(untagged)]
+    Other(String),
+}
+
+/// An authorization to create.
+///
+/// # Example
+/// ```
+/// use influxdb_client::api::{NewAuthorization, Permission, Resource};
+///
+/// // A token that can only write to one bucket
+/// let authorization = NewAuthorization::new()
+///     .with_description("ingest service")
+///     .with_permission(Permission::write(Resource::bucket("0b3a1fd4d2a0e8a1")));
+/// ```
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct NewAuthorization {
+    #[serde(rename = "orgID", skip_serializing_if = "Option::is_none")]
+    org_id: Option<String>,
+    #[serde(rename = "userID", skip_serializing_if = "Option::is_none")]
+    user_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    status: Option<Status>,
+    permissions: Vec<Permission>,
+}
+
+impl NewAuthorization {
+    /// An active authorization without permissions, in the organization of the client.
+    pub fn new() -> Self {
+        NewAuthorization::default()
+    }
+
+    /// Create the authorization in another organization than the one of the client.
+    pub fn with_org_id(mut self, org_id: impl Into<String>) -> Self {
+        self.org_id = Some(org_id.into());
+        self
+    }
+
+    /// Create the authorization for another user than the one of the client.
+    pub fn with_user_id(mut self, user_id: impl Into<String>) -> Self {
+        self.user_id = Some(user_id.into());
+        self
+    }
+
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn with_status(mut self, status: Status) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    pub fn with_permission(mut self, permission: Permission) -> Self {
+        self.permissions.push(permission);
+        self
+    }
+}
+
+#[derive(Deserialize)]
+struct AuthorizationList {
+    authorizations: Vec<Authorization>,
+}
+
+/// Authorization API, see [`Client::authorizations`].
+pub struct Authorizations<'a> {
+    client: &'a Client,
+}
+
+impl Client {
+    /// Create, list, activate, deactivate and delete authorizations.
+    pub fn authorizations(&self) -> Authorizations<'_> {
+        Authorizations { client: self }
+    }
+}
+
+impl Authorizations<'_> {
+    /// Create an authorization. Resources without an organization get the one of the authorization.
+    pub async fn create(
+        &self,
+        authorization: &NewAuthorization,
+    ) -> Result<Authorization, InfluxError> {
+        let mut authorization = authorization.clone();
+        let org_id = match &authorization.org_id {
+            Some(org_id) => org_id.clone(),
+            None => self.client.resolve_org_id().await?,
+        };
+        for permission in &mut authorization.permissions {
+            if permission.resource.org_id.is_none() {
+                permission.resource.org_id = Some(org_id.clone());
+            }
+        }
+        authorization.org_id = Some(org_id);
+
+        let request = self
+            .client
+            .api_request(Method::POST, "/api/v2/authorizations")
+            .json(&authorization);
+        self.client.send_json(request).await
+    }
+
+    /// List authorizations of the organization of the client, or all if it has none.
+    pub async fn list(&self) -> Result<Vec<Authorization>, InfluxError> {
+        let mut query = Vec::new();
+        if let Some(org_id) = self.client.configured_org_id().await? {
+            query.push(("orgID", org_id));
+        }
+
+        let request = self
+            .client
+            .api_request(Method::GET, "/api/v2/authorizations")
+            .query(&query);
+        let list: AuthorizationList = self.client.send_json(request).await?;
+        Ok(list.authorizations)
+    }
+
+    pub async fn get(&self, id: &str) -> Result<Authorization, InfluxError> {
+        let request = self
+            .client
+            .api_request(Method::GET, &format!("/api/v2/authorizations/{}", id));
+        self.client.send_json(request).await
+    }
+
+    pub async fn activate(&self, id: &str) -> Result<Authorization, InfluxError> {
+        self.set_status(id, Status::Active).await
+    }
+
+    pub async fn deactivate(&self, id: &str) -> Result<Authorization, InfluxError> {
+        self.set_status(id, Status::Inactive).await
+    }
+
+    pub async fn set_status(&self, id: &str, status: Status) -> Result<Authorization, InfluxError> {
+        let request = self
+            .client
+            .api_request(Method::PATCH, &format!("/api/v2/authorizations/{}", id))
+            .json(&serde_json::json!({ "status": status }));
+        self.client.send_json(request).await
+    }
+
+    pub async fn delete(&self, id: &str) -> Result<(), InfluxError> {
+        let request = self
+            .client
+            .api_request(Method::DELETE, &format!("/api/v2/authorizations/{}", id));
+        self.client.send_empty(request).await
+    }
+}
@@ -0,0 +1,207 @@
+//! Buckets, refer: <https://docs.influxdata.com/influxdb/v2.0/api/#tag/Buckets>
+use std::time::Duration;
+
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    api::{
+        labels::{self, Label},
+        ListOptions,
+    },
+    client::Client,
+    models::InfluxError,
+};
+
+/// A bucket as returned by the server.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Bucket {
+    pub id: String,
+    #[serde(rename = "orgID")]
+    pub org_id: String,
+    pub name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub retention_rules: Vec<RetentionRule>,
+    #[serde(default)]
+    pub created_at: Option<String>,
+    #[serde(default)]
+    pub updated_at: Option<String>,
+}
+
+/// How long data is kept in a bucket.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RetentionRule {
+    #[serde(rename = "type")]
+    pub kind: String,
+    /// Seconds to keep data for, 0 keeps it forever.
+    pub every_seconds: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shard_group_duration_seconds: Option<u64>,
+}
+
+impl RetentionRule {
+    /// Delete data older than `duration`.
+    pub fn expire(duration: Duration) -> Self {
+        RetentionRule {
+            kind: "expire".to_string(),
+            every_seconds: duration.as_secs(),
+            shard_group_duration_seconds: None,
+        }
+    }
+}
+
+/// A bucket to create.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NewBucket {
+    #[serde(rename = "orgID", skip_serializing_if = "Option::is_none")]
+    org_id: Option<String>,
+    name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    retention_rules: Vec<RetentionRule>,
+}
+
+impl NewBucket {
+    /// A bucket that keeps data forever, in the organization of the client.
+    pub fn new(name: impl Into<String>) -> Self {
+        NewBucket {
+            org_id: None,
+            name: name.into(),
+            description: None,
+            retention_rules: Vec::new(),
+        }
+    }
+
+    /// Create the bucket in another organization than the one of the client.
+    pub fn with_org_id(mut self, org_id: impl Into<String>) -> Self {
+        self.org_id = Some(org_id.into());
+        self
+    }
+
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn with_retention(mut self, rule: RetentionRule) -> Self {
+        self.retention_rules.push(rule);
+        self
+    }
+}
+
+/// Changes to a bucket. Only the given properties are changed.
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BucketUpdate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    retention_rules: Option<Vec<RetentionRule>>,
+}
+
+impl BucketUpdate {
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Replace the retention rules, an empty list keeps data forever.
+    pub fn with_retention_rules(mut self, rules: Vec<RetentionRule>) -> Self {
+        self.retention_rules = Some(rules);
+        self
+    }
+}
+
+#[derive(Deserialize)]
+struct BucketList {
+    buckets: Vec<Bucket>,
+}
+
+/// Bucket API, see [`Client::buckets`].
+pub struct Buckets<'a> {
+    client: &'a Client,
+}
+
+impl Client {
+    /// Create, list, update and delete buckets.
+    pub fn buckets(&self) -> Buckets<'_> {
+        Buckets { client: self }
+    }
+}
+
+impl Buckets<'_> {
+    pub async fn create(&self, bucket: &NewBucket) -> Result<Bucket, InfluxError> {
+        let mut bucket = bucket.clone();
+        if bucket.org_id.is_none() {
+            bucket.org_id = Some(self.client.resolve_org_id().await?);
+        }
+
+        let request = self
+            .client
+            .api_request(Method::POST, "/api/v2/buckets")
+            .json(&bucket);
+        self.client.send_json(request).await
+    }
+
+    /// List buckets of the organization of the client, or all buckets if it has none.
+    pub async fn list(&self, options: &ListOptions) -> Result<Vec<Bucket>, InfluxError> {
+        let mut query = options.query("name");
+        if let Some(org_id) = self.client.configured_org_id().await? {
+            query.push(("orgID", org_id));
+        }
+
+        let request = self
+            .client
+            .api_request(Method::GET, "/api/v2/buckets")
+            .query(&query);
+        let list: BucketList = self.client.send_json(request).await?;
+        Ok(list.buckets)
+    }
+
+    pub async fn get(&self, id: &str) -> Result<Bucket, InfluxError> {
+        let request = self
+            .client
+            .api_request(Method::GET, &format!("/api/v2/buckets/{}", id));
+        self.client.send_json(request).await
+    }
+
+    pub async fn update(&self, id: &str, update: &BucketUpdate) -> Result<Bucket, InfluxError> {
+        let request = self
+            .client
+            .api_request(Method::PATCH, &format!("/api/v2/buckets/{}", id))
+            .json(update);
+        self.client.send_json(request).await
+    }
+
+    pub async fn delete(&self, id: &str) -> Result<(), InfluxError> {
+        let request = self
+            .client
+            .api_request(Method::DELETE, &format!("/api/v2/buckets/{}", id));
+        self.client.send_empty(request).await
+    }
+
+    /// Labels attached to the bucket.
+    pub async fn labels(&self, id: &str) -> Result<Vec<Label>, InfluxError> {
+        labels::list_labels(self.client, &format!("/api/v2/buckets/{}", id)).await
+    }
+
+    pub async fn add_label(&self, id: &str, label_id: &str) -> Result<Label, InfluxError> {
+        labels::add_label(self.client, &format!("/api/v2/buckets/{}", id), label_id).await
+    }
+
+    pub async fn remove_label(&self, id: &str, label_id: &str) -> Result<(), InfluxError> {
+        labels::remove_label(self.client, &format!("/api/v2/buckets/{}", id), label_id).await
+    }
+}
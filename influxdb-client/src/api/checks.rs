@@ -0,0 +1,240 @@
+//! Threshold and deadman checks, refer: <https://docs.influxdata.com/influxdb/v2.0/api/#tag/Checks>
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    api::{ListOptions, Status},
+    client::Client,
+    models::InfluxError,
+};
+
+/// A check, as created or returned by the server.
+///
+/// # Example
+/// ```
+/// use influxdb_client::api::{Check, CheckKind, Level, Threshold};
+///
+/// let check = Check::new(
+///     "High CPU",
+///     r#"from(bucket: "telegraf") |> range(start: -1m) |> filter(fn: (r) => r._field == "usage_user")"#,
+///     CheckKind::Threshold {
+///         thresholds: vec![Threshold::Greater { value: 90.0, level: Level::Crit, all_values: false }],
+///     },
+/// )
+/// .with_every("1m");
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Check {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The organization of the client if not set.
+    #[serde(default, rename = "orgID", skip_serializing_if = "Option::is_none")]
+    pub org_id: Option<String>,
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<Status>,
+    pub query: CheckQuery,
+    /// Interval the check runs at, e.g. `1m`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub every: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub offset: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status_message_template: Option<String>,
+    #[serde(flatten)]
+    pub kind: CheckKind,
+}
+
+impl Check {
+    /// An active check of the result of `flux`.
+    pub fn new(name: impl Into<String>, flux: impl Into<String>, kind: CheckKind) -> Self {
+        Check {
+            id: None,
+            org_id: None,
+            name: name.into(),
+            description: None,
+            status: None,
+            query: CheckQuery { text: flux.into() },
+            every: None,
+            offset: None,
+            status_message_template: None,
+            kind,
+        }
+    }
+
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn with_status(mut self, status: Status) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Run the check at this interval, as Flux duration.
+    pub fn with_every(mut self, every: impl Into<String>) -> Self {
+        self.every = Some(every.into());
+        self
+    }
+
+    pub fn with_offset(mut self, offset: impl Into<String>) -> Self {
+        self.offset = Some(offset.into());
+        self
+    }
+
+    pub fn with_status_message_template(mut self, template: impl Into<String>) -> Self {
+        self.status_message_template = Some(template.into());
+        self
+    }
+}
+
+/// Flux query of a check.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CheckQuery {
+    pub text: String,
+}
+
+/// What a check tests.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum CheckKind {
+    /// Compare values to thresholds.
+    Threshold { thresholds: Vec<Threshold> },
+    /// Report when no data arrived for a while.
+    Deadman {
+        /// How long without data is reported, e.g. `90s`.
+        #[serde(rename = "timeSince")]
+        time_since: String,
+        /// How long to keep reporting, e.g. `10m`.
+        #[serde(default, rename = "staleTime", skip_serializing_if = "Option::is_none")]
+        stale_time: Option<String>,
+        #[serde(default, rename = "reportZero")]
+        report_zero: bool,
+        level: Level,
+    },
+    /// Any other type, e.g. `custom`, with its `type` and all fields the client does not know.
+    #[serde(untagged)]
+    Other(serde_json::Map<String, serde_json::Value>),
+}
+
+/// Threshold of a threshold check.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum Threshold {
+    Greater {
+        value: f64,
+        level: Level,
+        #[serde(default, rename = "allValues")]
+        all_values: bool,
+    },
+    Lesser {
+        value: f64,
+        level: Level,
+        #[serde(default, rename = "allValues")]
+        all_values: bool,
+    },
+    Range {
+        min: f64,
+        max: f64,
+        /// Whether values inside the range, rather than outside, are reported.
+        within: bool,
+        level: Level,
+        #[serde(default, rename = "allValues")]
+        all_values: bool,
+    },
+}
+
+/// Level of a check status.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum Level {
+    Unknown,
+    Ok,
+    Info,
+    Warn,
+    Crit,
+}
+
+#[derive(Deserialize)]
+struct CheckList {
+    checks: Vec<Check>,
+}
+
+/// Check API, see [`Client::checks`].
+pub struct Checks<'a> {
+    client: &'a Client,
+}
+
+impl Client {
+    /// Create, list, update and delete checks.
+    pub fn checks(&self) -> Checks<'_> {
+        Checks { client: self }
+    }
+}
+
+impl Checks<'_> {
+    pub async fn create(&self, check: &Check) -> Result<Check, InfluxError> {
+        let mut check = check.clone();
+        if check.org_id.is_none() {
+            check.org_id = Some(self.client.resolve_org_id().await?);
+        }
+
+        let request = self
+            .client
+            .api_request(Method::POST, "/api/v2/checks")
+            .json(&check);
+        self.client.send_json(request).await
+    }
+
+    /// List checks of the organization of the client.
+    ///
+    /// The server does not filter by name, so the name filter of `options` is applied to the
+    /// returned page. `after` is not supported.
+    pub async fn list(&self, options: &ListOptions) -> Result<Vec<Check>, InfluxError> {
+        let mut query = options.page_query();
+        query.push(("orgID", self.client.resolve_org_id().await?));
+
+        let request = self
+            .client
+            .api_request(Method::GET, "/api/v2/checks")
+            .query(&query);
+        let list: CheckList = self.client.send_json(request).await?;
+        Ok(list
+            .checks
+            .into_iter()
+            .filter(|check| options.matches_name(&check.name))
+            .collect())
+    }
+
+    pub async fn get(&self, id: &str) -> Result<Check, InfluxError> {
+        let request = self
+            .client
+            .api_request(Method::GET, &format!("/api/v2/checks/{}", id));
+        self.client.send_json(request).await
+    }
+
+    /// Replace the check with `check`.
+    pub async fn update(&self, id: &str, check: &Check) -> Result<Check, InfluxError> {
+        let mut check = check.clone();
+        if check.org_id.is_none() {
+            check.org_id = Some(self.client.resolve_org_id().await?);
+        }
+
+        let request = self
+            .client
+            .api_request(Method::PUT, &format!("/api/v2/checks/{}", id))
+            .json(&check);
+        self.client.send_json(request).await
+    }
+
+    pub async fn delete(&self, id: &str) -> Result<(), InfluxError> {
+        let request = self
+            .client
+            .api_request(Method::DELETE, &format!("/api/v2/checks/{}", id));
+        self.client.send_empty(request).await
+    }
+}
@@ -0,0 +1,294 @@
+//! Dashboards and their cells, refer: <https://docs.influxdata.com/influxdb/v2.0/api/#tag/Dashboards>
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{api::ListOptions, client::Client, models::InfluxError};
+
+/// A dashboard as returned by the server.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Dashboard {
+    pub id: String,
+    #[serde(rename = "orgID")]
+    pub org_id: String,
+    pub name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub cells: Vec<DashboardCell>,
+}
+
+/// Position and size of a view on a dashboard, in grid units.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct DashboardCell {
+    pub id: String,
+    #[serde(default)]
+    pub x: i32,
+    #[serde(default)]
+    pub y: i32,
+    #[serde(default)]
+    pub w: i32,
+    #[serde(default)]
+    pub h: i32,
+    #[serde(default, rename = "viewID")]
+    pub view_id: Option<String>,
+}
+
+/// A dashboard to create.
+#[derive(Debug, Clone, Serialize)]
+pub struct NewDashboard {
+    #[serde(rename = "orgID", skip_serializing_if = "Option::is_none")]
+    org_id: Option<String>,
+    name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+}
+
+impl NewDashboard {
+    /// An empty dashboard in the organization of the client.
+    pub fn new(name: impl Into<String>) -> Self {
+        NewDashboard {
+            org_id: None,
+            name: name.into(),
+            description: None,
+        }
+    }
+
+    /// Create the dashboard in another organization than the one of the client.
+    pub fn with_org_id(mut self, org_id: impl Into<String>) -> Self {
+        self.org_id = Some(org_id.into());
+        self
+    }
+
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+}
+
+/// Changes to a dashboard. Only the given properties are changed.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct DashboardUpdate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+}
+
+impl DashboardUpdate {
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+}
+
+/// Position and size of a cell to add or move.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct CellPosition {
+    pub x: i32,
+    pub y: i32,
+    pub w: i32,
+    pub h: i32,
+}
+
+impl CellPosition {
+    pub fn new(x: i32, y: i32, w: i32, h: i32) -> Self {
+        CellPosition { x, y, w, h }
+    }
+}
+
+/// What a cell shows.
+///
+/// The properties depend on the type of the view and are kept as JSON, refer:
+/// <https://docs.influxdata.com/influxdb/v2.0/api/#operation/GetDashboardsIDCellsIDView>
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct View {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    pub name: String,
+    pub properties: serde_json::Value,
+}
+
+impl View {
+    pub fn new(name: impl Into<String>, properties: serde_json::Value) -> Self {
+        View {
+            id: None,
+            name: name.into(),
+            properties,
+        }
+    }
+
+    /// A note written in markdown.
+    pub fn markdown(name: impl Into<String>, note: impl Into<String>) -> Self {
+        View::new(
+            name,
+            serde_json::json!({
+                "type": "markdown",
+                "shape": "chronograf-v2",
+                "note": note.into(),
+            }),
+        )
+    }
+}
+
+#[derive(Deserialize)]
+struct DashboardList {
+    dashboards: Vec<Dashboard>,
+}
+
+/// Dashboard API, see [`Client::dashboards`].
+pub struct Dashboards<'a> {
+    client: &'a Client,
+}
+
+impl Client {
+    /// Create, list, update and delete dashboards and their cells.
+    pub fn dashboards(&self) -> Dashboards<'_> {
+        Dashboards { client: self }
+    }
+}
+
+impl Dashboards<'_> {
+    pub async fn create(&self, dashboard: &NewDashboard) -> Result<Dashboard, InfluxError> {
+        let mut dashboard = dashboard.clone();
+        if dashboard.org_id.is_none() {
+            dashboard.org_id = Some(self.client.resolve_org_id().await?);
+        }
+
+        let request = self
+            .client
+            .api_request(Method::POST, "/api/v2/dashboards")
+            .json(&dashboard);
+        self.client.send_json(request).await
+    }
+
+    /// List dashboards of the organization of the client, or all dashboards if it has none.
+    ///
+    /// The server does not filter by name, so the name filter of `options` is applied to the
+    /// returned page. `after` is not supported.
+    pub async fn list(&self, options: &ListOptions) -> Result<Vec<Dashboard>, InfluxError> {
+        let mut query = options.page_query();
+        if let Some(org_id) = self.client.configured_org_id().await? {
+            query.push(("orgID", org_id));
+        }
+
+        let request = self
+            .client
+            .api_request(Method::GET, "/api/v2/dashboards")
+            .query(&query);
+        let list: DashboardList = self.client.send_json(request).await?;
+        Ok(list
+            .dashboards
+            .into_iter()
+            .filter(|dashboard| options.matches_name(&dashboard.name))
+            .collect())
+    }
+
+    pub async fn get(&self, id: &str) -> Result<Dashboard, InfluxError> {
+        let request = self
+            .client
+            .api_request(Method::GET, &format!("/api/v2/dashboards/{}", id));
+        self.client.send_json(request).await
+    }
+
+    pub async fn update(
+        &self,
+        id: &str,
+        update: &DashboardUpdate,
+    ) -> Result<Dashboard, InfluxError> {
+        let request = self
+            .client
+            .api_request(Method::PATCH, &format!("/api/v2/dashboards/{}", id))
+            .json(update);
+        self.client.send_json(request).await
+    }
+
+    pub async fn delete(&self, id: &str) -> Result<(), InfluxError> {
+        let request = self
+            .client
+            .api_request(Method::DELETE, &format!("/api/v2/dashboards/{}", id));
+        self.client.send_empty(request).await
+    }
+
+    /// Add a cell showing `view` to the dashboard.
+    ///
+    /// The cell is deleted again if its view cannot be set.
+    pub async fn add_cell(
+        &self,
+        id: &str,
+        position: CellPosition,
+        view: &View,
+    ) -> Result<DashboardCell, InfluxError> {
+        let request = self
+            .client
+            .api_request(Method::POST, &format!("/api/v2/dashboards/{}/cells", id))
+            .json(&serde_json::json!({
+                "name": view.name,
+                "x": position.x,
+                "y": position.y,
+                "w": position.w,
+                "h": position.h,
+            }));
+        let cell: DashboardCell = self.client.send_json(request).await?;
+        if let Err(error) = self.update_view(id, &cell.id, view).await {
+            // Report why the view failed rather than a failure of the cleanup
+            let _ = self.delete_cell(id, &cell.id).await;
+            return Err(error);
+        }
+        Ok(cell)
+    }
+
+    /// Move or resize a cell.
+    pub async fn update_cell(
+        &self,
+        id: &str,
+        cell_id: &str,
+        position: CellPosition,
+    ) -> Result<DashboardCell, InfluxError> {
+        let request = self
+            .client
+            .api_request(Method::PATCH, &cell_path(id, cell_id))
+            .json(&position);
+        self.client.send_json(request).await
+    }
+
+    pub async fn delete_cell(&self, id: &str, cell_id: &str) -> Result<(), InfluxError> {
+        let request = self
+            .client
+            .api_request(Method::DELETE, &cell_path(id, cell_id));
+        self.client.send_empty(request).await
+    }
+
+    /// View shown by a cell.
+    pub async fn view(&self, id: &str, cell_id: &str) -> Result<View, InfluxError> {
+        let request = self
+            .client
+            .api_request(Method::GET, &format!("{}/view", cell_path(id, cell_id)));
+        self.client.send_json(request).await
+    }
+
+    /// Replace the view shown by a cell.
+    pub async fn update_view(
+        &self,
+        id: &str,
+        cell_id: &str,
+        view: &View,
+    ) -> Result<View, InfluxError> {
+        let request = self
+            .client
+            .api_request(Method::PATCH, &format!("{}/view", cell_path(id, cell_id)))
+            .json(&serde_json::json!({
+                "name": view.name,
+                "properties": view.properties,
+            }));
+        self.client.send_json(request).await
+    }
+}
+
+fn cell_path(id: &str, cell_id: &str) -> String {
+    format!("/api/v2/dashboards/{}/cells/{}", id, cell_id)
+}
@@ -0,0 +1,211 @@
+//! Database and retention policy mappings of the 1.x compatibility API, refer: <https://docs.influxdata.com/influxdb/v2.0/api/#tag/DBRPs>
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{client::Client, models::InfluxError};
+
+/// Mapping of a 1.x database and retention policy onto a bucket, as returned by the server.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Dbrp {
+    pub id: String,
+    #[serde(rename = "orgID")]
+    pub org_id: String,
+    #[serde(rename = "bucketID")]
+    pub bucket_id: String,
+    pub database: String,
+    pub retention_policy: String,
+    /// Whether this retention policy is used when none is given.
+    #[serde(default)]
+    pub default: bool,
+    /// Created by the server for a bucket named `database/retention_policy`.
+    #[serde(default, rename = "virtual")]
+    pub is_virtual: bool,
+}
+
+/// A mapping to create.
+#[derive(Debug, Clone, Serialize)]
+pub struct NewDbrp {
+    #[serde(rename = "orgID", skip_serializing_if = "Option::is_none")]
+    org_id: Option<String>,
+    #[serde(rename = "bucketID")]
+    bucket_id: String,
+    database: String,
+    retention_policy: String,
+    default: bool,
+}
+
+impl NewDbrp {
+    /// Map `database` and `retention_policy` onto the bucket, in the organization of the client.
+    pub fn new(
+        bucket_id: impl Into<String>,
+        database: impl Into<String>,
+        retention_policy: impl Into<String>,
+    ) -> Self {
+        NewDbrp {
+            org_id: None,
+            bucket_id: bucket_id.into(),
+            database: database.into(),
+            retention_policy: retention_policy.into(),
+            default: false,
+        }
+    }
+
+    /// Create the mapping in another organization than the one of the client.
+    pub fn with_org_id(mut self, org_id: impl Into<String>) -> Self {
+        self.org_id = Some(org_id.into());
+        self
+    }
+
+    /// Use this retention policy when writes and queries of the database give none.
+    pub fn with_default(mut self, default: bool) -> Self {
+        self.default = default;
+        self
+    }
+}
+
+/// Changes to a mapping. Only the given properties are changed.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct DbrpUpdate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    retention_policy: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    default: Option<bool>,
+}
+
+impl DbrpUpdate {
+    pub fn with_retention_policy(mut self, retention_policy: impl Into<String>) -> Self {
+        self.retention_policy = Some(retention_policy.into());
+        self
+    }
+
+    pub fn with_default(mut self, default: bool) -> Self {
+        self.default = Some(default);
+        self
+    }
+}
+
+/// Filter of [`Dbrps::list`], all mappings of the organization by default.
+#[derive(Debug, Clone, Default)]
+pub struct DbrpFilter {
+    bucket_id: Option<String>,
+    database: Option<String>,
+    retention_policy: Option<String>,
+    default: Option<bool>,
+}
+
+impl DbrpFilter {
+    pub fn with_bucket_id(mut self, bucket_id: impl Into<String>) -> Self {
+        self.bucket_id = Some(bucket_id.into());
+        self
+    }
+
+    pub fn with_database(mut self, database: impl Into<String>) -> Self {
+        self.database = Some(database.into());
+        self
+    }
+
+    pub fn with_retention_policy(mut self, retention_policy: impl Into<String>) -> Self {
+        self.retention_policy = Some(retention_policy.into());
+        self
+    }
+
+    pub fn with_default(mut self, default: bool) -> Self {
+        self.default = Some(default);
+        self
+    }
+
+    fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(bucket_id) = &self.bucket_id {
+            query.push(("bucketID", bucket_id.clone()));
+        }
+        if let Some(database) = &self.database {
+            query.push(("db", database.clone()));
+        }
+        if let Some(retention_policy) = &self.retention_policy {
+            query.push(("rp", retention_policy.clone()));
+        }
+        if let Some(default) = self.default {
+            query.push(("default", default.to_string()));
+        }
+        query
+    }
+}
+
+#[derive(Deserialize)]
+struct DbrpList {
+    content: Vec<Dbrp>,
+}
+
+#[derive(Deserialize)]
+struct DbrpResponse {
+    content: Dbrp,
+}
+
+/// DBRP mapping API, see [`Client::dbrps`].
+pub struct Dbrps<'a> {
+    client: &'a Client,
+}
+
+impl Client {
+    /// Create, list, update and delete mappings of 1.x databases and retention policies
+    /// onto buckets, used by [`Client::insert_points_v1`] and [`Client::query_v1`].
+    pub fn dbrps(&self) -> Dbrps<'_> {
+        Dbrps { client: self }
+    }
+}
+
+impl Dbrps<'_> {
+    pub async fn create(&self, dbrp: &NewDbrp) -> Result<Dbrp, InfluxError> {
+        let mut dbrp = dbrp.clone();
+        if dbrp.org_id.is_none() {
+            dbrp.org_id = Some(self.client.resolve_org_id().await?);
+        }
+
+        let request = self
+            .client
+            .api_request(Method::POST, "/api/v2/dbrps")
+            .json(&dbrp);
+        self.client.send_json(request).await
+    }
+
+    /// List mappings of the organization of the client.
+    pub async fn list(&self, filter: &DbrpFilter) -> Result<Vec<Dbrp>, InfluxError> {
+        let mut query = filter.query();
+        query.push(("orgID", self.client.resolve_org_id().await?));
+
+        let request = self
+            .client
+            .api_request(Method::GET, "/api/v2/dbrps")
+            .query(&query);
+        let list: DbrpList = self.client.send_json(request).await?;
+        Ok(list.content)
+    }
+
+    pub async fn get(&self, id: &str) -> Result<Dbrp, InfluxError> {
+        let request = self
+            .client
+            .api_request(Method::GET, &format!("/api/v2/dbrps/{}", id))
+            .query(&[("orgID", self.client.resolve_org_id().await?)]);
+        let response: DbrpResponse = self.client.send_json(request).await?;
+        Ok(response.content)
+    }
+
+    pub async fn update(&self, id: &str, update: &DbrpUpdate) -> Result<Dbrp, InfluxError> {
+        let request = self
+            .client
+            .api_request(Method::PATCH, &format!("/api/v2/dbrps/{}", id))
+            .query(&[("orgID", self.client.resolve_org_id().await?)])
+            .json(update);
+        let response: DbrpResponse = self.client.send_json(request).await?;
+        Ok(response.content)
+    }
+
+    pub async fn delete(&self, id: &str) -> Result<(), InfluxError> {
+        let request = self
+            .client
+            .api_request(Method::DELETE, &format!("/api/v2/dbrps/{}", id))
+            .query(&[("orgID", self.client.resolve_org_id().await?)]);
+        self.client.send_empty(request).await
+    }
+}
@@ -0,0 +1,192 @@
+//! Labels, refer: <https://docs.influxdata.com/influxdb/v2.0/api/#tag/Labels>
+use std::collections::HashMap;
+
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{client::Client, models::InfluxError};
+
+/// A label as returned by the server.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Label {
+    pub id: String,
+    #[serde(default, rename = "orgID")]
+    pub org_id: Option<String>,
+    pub name: String,
+    /// Free-form properties, the UI uses `color` and `description`.
+    #[serde(default)]
+    pub properties: HashMap<String, String>,
+}
+
+/// A label to create.
+#[derive(Debug, Clone, Serialize)]
+pub struct NewLabel {
+    #[serde(rename = "orgID", skip_serializing_if = "Option::is_none")]
+    org_id: Option<String>,
+    name: String,
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    properties: HashMap<String, String>,
+}
+
+impl NewLabel {
+    /// A label in the organization of the client.
+    pub fn new(name: impl Into<String>) -> Self {
+        NewLabel {
+            org_id: None,
+            name: name.into(),
+            properties: HashMap::new(),
+        }
+    }
+
+    /// Create the label in another organization than the one of the client.
+    pub fn with_org_id(mut self, org_id: impl Into<String>) -> Self {
+        self.org_id = Some(org_id.into());
+        self
+    }
+
+    /// Color shown in the UI, e.g. `#326BBA`.
+    pub fn with_color(self, color: impl Into<String>) -> Self {
+        self.with_property("color", color)
+    }
+
+    pub fn with_description(self, description: impl Into<String>) -> Self {
+        self.with_property("description", description)
+    }
+
+    pub fn with_property(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.properties.insert(key.into(), value.into());
+        self
+    }
+}
+
+/// Changes to a label. Only the given properties are changed, an empty value removes one.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct LabelUpdate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    properties: HashMap<String, String>,
+}
+
+impl LabelUpdate {
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    pub fn with_property(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.properties.insert(key.into(), value.into());
+        self
+    }
+}
+
+#[derive(Deserialize)]
+struct LabelResponse {
+    label: Label,
+}
+
+#[derive(Deserialize)]
+struct LabelList {
+    labels: Vec<Label>,
+}
+
+/// Label API, see [`Client::labels`].
+///
+/// Labels are attached to resources through their APIs, e.g. [`Buckets::add_label`](crate::api::Buckets::add_label).
+pub struct Labels<'a> {
+    client: &'a Client,
+}
+
+impl Client {
+    /// Create, list, update and delete labels.
+    pub fn labels(&self) -> Labels<'_> {
+        Labels { client: self }
+    }
+}
+
+impl Labels<'_> {
+    pub async fn create(&self, label: &NewLabel) -> Result<Label, InfluxError> {
+        let mut label = label.clone();
+        if label.org_id.is_none() {
+            label.org_id = Some(self.client.resolve_org_id().await?);
+        }
+
+        let request = self
+            .client
+            .api_request(Method::POST, "/api/v2/labels")
+            .json(&label);
+        let response: LabelResponse = self.client.send_json(request).await?;
+        Ok(response.label)
+    }
+
+    /// List labels of the organization of the client, or all labels if it has none.
+    ///
+    /// The server cannot filter labels by name or page them.
+    pub async fn list(&self) -> Result<Vec<Label>, InfluxError> {
+        let mut query = Vec::new();
+        if let Some(org_id) = self.client.configured_org_id().await? {
+            query.push(("orgID", org_id));
+        }
+
+        let request = self
+            .client
+            .api_request(Method::GET, "/api/v2/labels")
+            .query(&query);
+        let list: LabelList = self.client.send_json(request).await?;
+        Ok(list.labels)
+    }
+
+    pub async fn get(&self, id: &str) -> Result<Label, InfluxError> {
+        let request = self
+            .client
+            .api_request(Method::GET, &format!("/api/v2/labels/{}", id));
+        let response: LabelResponse = self.client.send_json(request).await?;
+        Ok(response.label)
+    }
+
+    pub async fn update(&self, id: &str, update: &LabelUpdate) -> Result<Label, InfluxError> {
+        let request = self
+            .client
+            .api_request(Method::PATCH, &format!("/api/v2/labels/{}", id))
+            .json(update);
+        let response: LabelResponse = self.client.send_json(request).await?;
+        Ok(response.label)
+    }
+
+    pub async fn delete(&self, id: &str) -> Result<(), InfluxError> {
+        let request = self
+            .client
+            .api_request(Method::DELETE, &format!("/api/v2/labels/{}", id));
+        self.client.send_empty(request).await
+    }
+}
+
+/// Labels of the resource at `path`, e.g. `/api/v2/buckets/{id}`.
+pub(crate) async fn list_labels(client: &Client, path: &str) -> Result<Vec<Label>, InfluxError> {
+    let request = client.api_request(Method::GET, &format!("{}/labels", path));
+    let list: LabelList = client.send_json(request).await?;
+    Ok(list.labels)
+}
+
+/// Attach a label to the resource at `path`.
+pub(crate) async fn add_label(
+    client: &Client,
+    path: &str,
+    label_id: &str,
+) -> Result<Label, InfluxError> {
+    let request = client
+        .api_request(Method::POST, &format!("{}/labels", path))
+        .json(&serde_json::json!({ "labelID": label_id }));
+    let response: LabelResponse = client.send_json(request).await?;
+    Ok(response.label)
+}
+
+/// Detach a label from the resource at `path`.
+pub(crate) async fn remove_label(
+    client: &Client,
+    path: &str,
+    label_id: &str,
+) -> Result<(), InfluxError> {
+    let request = client.api_request(Method::DELETE, &format!("{}/labels/{}", path, label_id));
+    client.send_empty(request).await
+}
@@ -0,0 +1,111 @@
+//! Management APIs of InfluxDB, reached through accessors on [`Client`](crate::Client).
+//!
+//! # Example
+//! ```no_run
+//! # async fn example() -> Result<(), influxdb_client::InfluxError> {
+//! use std::time::Duration;
+//! use influxdb_client::{api::{NewBucket, RetentionRule}, Client};
+//!
+//! let client = Client::new("http://localhost:8086", "token").unwrap().with_org_id("168f31904923e853");
+//! let bucket = client
+//!     .buckets()
+//!     .create(&NewBucket::new("customer-42").with_retention(RetentionRule::expire(Duration::from_secs(86400))))
+//!     .await?;
+//! # Ok(())
+//! # }
+//! ```
+mod authorizations;
+mod buckets;
+mod checks;
+mod dashboards;
+mod dbrps;
+mod labels;
+mod notifications;
+mod orgs;
+mod tasks;
+mod users;
+mod variables;
+
+pub use authorizations::{
+    Action, Authorization, Authorizations, NewAuthorization, Permission, Resource, ResourceType,
+};
+pub use buckets::{Bucket, BucketUpdate, Buckets, NewBucket, RetentionRule};
+pub use checks::{Check, CheckKind, CheckQuery, Checks, Level, Threshold};
+pub use dashboards::{
+    CellPosition, Dashboard, DashboardCell, DashboardUpdate, Dashboards, NewDashboard, View,
+};
+pub use dbrps::{Dbrp, DbrpFilter, DbrpUpdate, Dbrps, NewDbrp};
+pub use labels::{Label, LabelUpdate, Labels, NewLabel};
+pub use notifications::{
+    EndpointKind, HttpAuth, HttpMethod, NotificationEndpoint, NotificationEndpoints,
+    NotificationRule, NotificationRules, RuleKind, StatusRule, TagOperator, TagRule,
+};
+pub use orgs::{Member, Organization, OrganizationUpdate, Orgs, Role};
+pub use tasks::{LogEvent, NewTask, Run, RunStatus, Schedule, Task, TaskUpdate, Tasks};
+pub use users::{Status, User, UserUpdate, Users};
+pub use variables::{Variable, VariableArguments, VariableQuery, Variables};
+
+/// Name filter and page of list calls.
+#[derive(Debug, Clone, Default)]
+pub struct ListOptions {
+    name: Option<String>,
+    offset: Option<usize>,
+    limit: Option<usize>,
+    after: Option<String>,
+}
+
+impl ListOptions {
+    /// Only the resource with this name.
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Skip this many resources.
+    pub fn with_offset(mut self, offset: usize) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Return at most this many resources. The server defaults to 20.
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Only resources after the one with this ID. Not supported by all resources.
+    pub fn with_after(mut self, id: impl Into<String>) -> Self {
+        self.after = Some(id.into());
+        self
+    }
+
+    /// Query parameters, with the name filter as `name_key`.
+    pub(crate) fn query(&self, name_key: &'static str) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(name) = &self.name {
+            query.push((name_key, name.clone()));
+        }
+        query.extend(self.page_query());
+        if let Some(after) = &self.after {
+            query.push(("after", after.clone()));
+        }
+        query
+    }
+
+    /// Query parameters of resources that only support `offset` and `limit`.
+    pub(crate) fn page_query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(offset) = self.offset {
+            query.push(("offset", offset.to_string()));
+        }
+        if let Some(limit) = self.limit {
+            query.push(("limit", limit.to_string()));
+        }
+        query
+    }
+
+    /// Whether `name` passes the name filter, for resources the server cannot filter by name.
+    pub(crate) fn matches_name(&self, name: &str) -> bool {
+        self.name.as_deref().is_none_or(|filter| filter == name)
+    }
+}
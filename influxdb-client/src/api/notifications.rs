@@ -0,0 +1,467 @@
+//! Notification endpoints and rules, refer: <https://docs.influxdata.com/influxdb/v2.0/api/#tag/NotificationEndpoints>
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    api::{Level, ListOptions, Status},
+    client::Client,
+    models::InfluxError,
+};
+
+/// Where notifications are sent, as created or returned by the server.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NotificationEndpoint {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The organization of the client if not set.
+    #[serde(default, rename = "orgID", skip_serializing_if = "Option::is_none")]
+    pub org_id: Option<String>,
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<Status>,
+    #[serde(flatten)]
+    pub kind: EndpointKind,
+}
+
+impl NotificationEndpoint {
+    /// An active endpoint.
+    pub fn new(name: impl Into<String>, kind: EndpointKind) -> Self {
+        NotificationEndpoint {
+            id: None,
+            org_id: None,
+            name: name.into(),
+            description: None,
+            status: None,
+            kind,
+        }
+    }
+
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn with_status(mut self, status: Status) -> Self {
+        self.status = Some(status);
+        self
+    }
+}
+
+/// Type of a notification endpoint.
+///
+/// Secrets such as tokens and passwords are returned by the server as secret references.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum EndpointKind {
+    Slack {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        url: Option<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        token: Option<String>,
+    },
+    Http {
+        url: String,
+        method: HttpMethod,
+        #[serde(rename = "authMethod")]
+        auth_method: HttpAuth,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        username: Option<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        password: Option<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        token: Option<String>,
+        #[serde(
+            default,
+            rename = "contentTemplate",
+            skip_serializing_if = "Option::is_none"
+        )]
+        content_template: Option<String>,
+    },
+    /// Any other type, e.g. `pagerduty` or `telegram`, with its `type` and all fields the
+    /// client does not know.
+    #[serde(untagged)]
+    Other(serde_json::Map<String, serde_json::Value>),
+}
+
+impl EndpointKind {
+    /// Slack incoming webhook.
+    pub fn slack(url: impl Into<String>) -> Self {
+        EndpointKind::Slack {
+            url: Some(url.into()),
+            token: None,
+        }
+    }
+
+    /// HTTP POST without authentication.
+    pub fn http(url: impl Into<String>) -> Self {
+        EndpointKind::Http {
+            url: url.into(),
+            method: HttpMethod::Post,
+            auth_method: HttpAuth::None,
+            username: None,
+            password: None,
+            token: None,
+            content_template: None,
+        }
+    }
+
+    /// HTTP POST with a bearer token.
+    pub fn http_bearer(url: impl Into<String>, token: impl Into<String>) -> Self {
+        EndpointKind::Http {
+            url: url.into(),
+            method: HttpMethod::Post,
+            auth_method: HttpAuth::Bearer,
+            username: None,
+            password: None,
+            token: Some(token.into()),
+            content_template: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum HttpMethod {
+    Post,
+    Get,
+    Put,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HttpAuth {
+    None,
+    Basic,
+    Bearer,
+}
+
+/// When and where notifications are sent, as created or returned by the server.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationRule {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The organization of the client if not set.
+    #[serde(default, rename = "orgID", skip_serializing_if = "Option::is_none")]
+    pub org_id: Option<String>,
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Active rules are created by default.
+    pub status: Status,
+    #[serde(rename = "endpointID")]
+    pub endpoint_id: String,
+    /// Interval the rule runs at, e.g. `1m`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub every: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub offset: Option<String>,
+    #[serde(default)]
+    pub status_rules: Vec<StatusRule>,
+    #[serde(default)]
+    pub tag_rules: Vec<TagRule>,
+    #[serde(flatten)]
+    pub kind: RuleKind,
+}
+
+impl NotificationRule {
+    /// An active rule sending to the endpoint with `endpoint_id`.
+    pub fn new(name: impl Into<String>, endpoint_id: impl Into<String>, kind: RuleKind) -> Self {
+        NotificationRule {
+            id: None,
+            org_id: None,
+            name: name.into(),
+            description: None,
+            status: Status::Active,
+            endpoint_id: endpoint_id.into(),
+            every: None,
+            offset: None,
+            status_rules: Vec::new(),
+            tag_rules: Vec::new(),
+            kind,
+        }
+    }
+
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn with_status(mut self, status: Status) -> Self {
+        self.status = status;
+        self
+    }
+
+    /// Run the rule at this interval, as Flux duration.
+    pub fn with_every(mut self, every: impl Into<String>) -> Self {
+        self.every = Some(every.into());
+        self
+    }
+
+    pub fn with_offset(mut self, offset: impl Into<String>) -> Self {
+        self.offset = Some(offset.into());
+        self
+    }
+
+    /// Notify about statuses of this level.
+    pub fn with_status_rule(mut self, rule: StatusRule) -> Self {
+        self.status_rules.push(rule);
+        self
+    }
+
+    /// Only notify about statuses with these tags.
+    pub fn with_tag_rule(mut self, rule: TagRule) -> Self {
+        self.tag_rules.push(rule);
+        self
+    }
+}
+
+/// Type of a notification rule, matching the type of its endpoint.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum RuleKind {
+    Slack {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        channel: Option<String>,
+        #[serde(rename = "messageTemplate")]
+        message_template: String,
+    },
+    Http,
+    /// Any other type, e.g. `pagerduty` or `telegram`, with its `type` and all fields the
+    /// client does not know.
+    #[serde(untagged)]
+    Other(serde_json::Map<String, serde_json::Value>),
+}
+
+/// Matches statuses of a level, optionally only when changing from another level.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatusRule {
+    pub current_level: Level,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub previous_level: Option<Level>,
+}
+
+impl StatusRule {
+    pub fn level(level: Level) -> Self {
+        StatusRule {
+            current_level: level,
+            previous_level: None,
+        }
+    }
+
+    pub fn change(from: Level, to: Level) -> Self {
+        StatusRule {
+            current_level: to,
+            previous_level: Some(from),
+        }
+    }
+}
+
+/// Matches statuses by tag.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TagRule {
+    pub key: String,
+    pub value: String,
+    pub operator: TagOperator,
+}
+
+impl TagRule {
+    pub fn equal(key: impl Into<String>, value: impl Into<String>) -> Self {
+        TagRule {
+            key: key.into(),
+            value: value.into(),
+            operator: TagOperator::Equal,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TagOperator {
+    Equal,
+    NotEqual,
+    EqualRegex,
+    NotEqualRegex,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct EndpointList {
+    notification_endpoints: Vec<NotificationEndpoint>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RuleList {
+    notification_rules: Vec<NotificationRule>,
+}
+
+/// Notification endpoint API, see [`Client::notification_endpoints`].
+pub struct NotificationEndpoints<'a> {
+    client: &'a Client,
+}
+
+/// Notification rule API, see [`Client::notification_rules`].
+pub struct NotificationRules<'a> {
+    client: &'a Client,
+}
+
+impl Client {
+    /// Create, list, update and delete notification endpoints.
+    pub fn notification_endpoints(&self) -> NotificationEndpoints<'_> {
+        NotificationEndpoints { client: self }
+    }
+
+    /// Create, list, update and delete notification rules.
+    pub fn notification_rules(&self) -> NotificationRules<'_> {
+        NotificationRules { client: self }
+    }
+}
+
+impl NotificationEndpoints<'_> {
+    pub async fn create(
+        &self,
+        endpoint: &NotificationEndpoint,
+    ) -> Result<NotificationEndpoint, InfluxError> {
+        let mut endpoint = endpoint.clone();
+        if endpoint.org_id.is_none() {
+            endpoint.org_id = Some(self.client.resolve_org_id().await?);
+        }
+
+        let request = self
+            .client
+            .api_request(Method::POST, "/api/v2/notificationEndpoints")
+            .json(&endpoint);
+        self.client.send_json(request).await
+    }
+
+    /// List endpoints of the organization of the client.
+    ///
+    /// The server does not filter by name, so the name filter of `options` is applied to the
+    /// returned page. `after` is not supported.
+    pub async fn list(
+        &self,
+        options: &ListOptions,
+    ) -> Result<Vec<NotificationEndpoint>, InfluxError> {
+        let mut query = options.page_query();
+        query.push(("orgID", self.client.resolve_org_id().await?));
+
+        let request = self
+            .client
+            .api_request(Method::GET, "/api/v2/notificationEndpoints")
+            .query(&query);
+        let list: EndpointList = self.client.send_json(request).await?;
+        Ok(list
+            .notification_endpoints
+            .into_iter()
+            .filter(|endpoint| options.matches_name(&endpoint.name))
+            .collect())
+    }
+
+    pub async fn get(&self, id: &str) -> Result<NotificationEndpoint, InfluxError> {
+        let request = self.client.api_request(
+            Method::GET,
+            &format!("/api/v2/notificationEndpoints/{}", id),
+        );
+        self.client.send_json(request).await
+    }
+
+    /// Replace the endpoint with `endpoint`.
+    pub async fn update(
+        &self,
+        id: &str,
+        endpoint: &NotificationEndpoint,
+    ) -> Result<NotificationEndpoint, InfluxError> {
+        let mut endpoint = endpoint.clone();
+        if endpoint.org_id.is_none() {
+            endpoint.org_id = Some(self.client.resolve_org_id().await?);
+        }
+
+        let request = self
+            .client
+            .api_request(
+                Method::PUT,
+                &format!("/api/v2/notificationEndpoints/{}", id),
+            )
+            .json(&endpoint);
+        self.client.send_json(request).await
+    }
+
+    pub async fn delete(&self, id: &str) -> Result<(), InfluxError> {
+        let request = self.client.api_request(
+            Method::DELETE,
+            &format!("/api/v2/notificationEndpoints/{}", id),
+        );
+        self.client.send_empty(request).await
+    }
+}
+
+impl NotificationRules<'_> {
+    pub async fn create(&self, rule: &NotificationRule) -> Result<NotificationRule, InfluxError> {
+        let mut rule = rule.clone();
+        if rule.org_id.is_none() {
+            rule.org_id = Some(self.client.resolve_org_id().await?);
+        }
+
+        let request = self
+            .client
+            .api_request(Method::POST, "/api/v2/notificationRules")
+            .json(&rule);
+        self.client.send_json(request).await
+    }
+
+    /// List rules of the organization of the client.
+    ///
+    /// The server does not filter by name, so the name filter of `options` is applied to the
+    /// returned page. `after` is not supported.
+    pub async fn list(&self, options: &ListOptions) -> Result<Vec<NotificationRule>, InfluxError> {
+        let mut query = options.page_query();
+        query.push(("orgID", self.client.resolve_org_id().await?));
+
+        let request = self
+            .client
+            .api_request(Method::GET, "/api/v2/notificationRules")
+            .query(&query);
+        let list: RuleList = self.client.send_json(request).await?;
+        Ok(list
+            .notification_rules
+            .into_iter()
+            .filter(|rule| options.matches_name(&rule.name))
+            .collect())
+    }
+
+    pub async fn get(&self, id: &str) -> Result<NotificationRule, InfluxError> {
+        let request = self
+            .client
+            .api_request(Method::GET, &format!("/api/v2/notificationRules/{}", id));
+        self.client.send_json(request).await
+    }
+
+    /// Replace the rule with `rule`.
+    pub async fn update(
+        &self,
+        id: &str,
+        rule: &NotificationRule,
+    ) -> Result<NotificationRule, InfluxError> {
+        let mut rule = rule.clone();
+        if rule.org_id.is_none() {
+            rule.org_id = Some(self.client.resolve_org_id().await?);
+        }
+
+        let request = self
+            .client
+            .api_request(Method::PUT, &format!("/api/v2/notificationRules/{}", id))
+            .json(&rule);
+        self.client.send_json(request).await
+    }
+
+    pub async fn delete(&self, id: &str) -> Result<(), InfluxError> {
+        let request = self
+            .client
+            .api_request(Method::DELETE, &format!("/api/v2/notificationRules/{}", id));
+        self.client.send_empty(request).await
+    }
+}
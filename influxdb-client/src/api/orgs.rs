@@ -0,0 +1,214 @@
+//! Organizations and their members, refer: <https://docs.influxdata.com/influxdb/v2.0/api/#tag/Organizations>
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    api::{ListOptions, Status},
+    client::Client,
+    models::InfluxError,
+};
+
+/// An organization as returned by the server.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Organization {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub created_at: Option<String>,
+    #[serde(default)]
+    pub updated_at: Option<String>,
+}
+
+/// Changes to an organization. Only the given properties are changed.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct OrganizationUpdate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+}
+
+impl OrganizationUpdate {
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+}
+
+/// Role of a user in a resource.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Role {
+    Member,
+    Owner,
+}
+
+/// A user with a role in a resource.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Member {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub status: Option<Status>,
+    pub role: Role,
+}
+
+#[derive(Serialize)]
+struct NewOrganization<'a> {
+    name: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<&'a str>,
+}
+
+#[derive(Deserialize)]
+struct OrganizationList {
+    orgs: Vec<Organization>,
+}
+
+#[derive(Deserialize)]
+struct MemberList {
+    users: Vec<Member>,
+}
+
+/// Organization API, see [`Client::orgs`].
+pub struct Orgs<'a> {
+    client: &'a Client,
+}
+
+impl Client {
+    /// Create, list, update and delete organizations and manage their members and owners.
+    pub fn orgs(&self) -> Orgs<'_> {
+        Orgs { client: self }
+    }
+}
+
+impl Orgs<'_> {
+    pub async fn create(
+        &self,
+        name: &str,
+        description: Option<&str>,
+    ) -> Result<Organization, InfluxError> {
+        let request = self
+            .client
+            .api_request(Method::POST, "/api/v2/orgs")
+            .json(&NewOrganization { name, description });
+        self.client.send_json(request).await
+    }
+
+    pub async fn list(&self, options: &ListOptions) -> Result<Vec<Organization>, InfluxError> {
+        let request = self
+            .client
+            .api_request(Method::GET, "/api/v2/orgs")
+            .query(&options.query("org"));
+        let list: OrganizationList = self.client.send_json(request).await?;
+        Ok(list.orgs)
+    }
+
+    /// The organization with this name, if there is one.
+    pub async fn find_by_name(&self, name: &str) -> Result<Option<Organization>, InfluxError> {
+        match self.list(&ListOptions::default().with_name(name)).await {
+            Ok(orgs) => Ok(orgs.into_iter().find(|org| org.name == name)),
+            Err(InfluxError::NotFound(_)) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub async fn get(&self, id: &str) -> Result<Organization, InfluxError> {
+        let request = self
+            .client
+            .api_request(Method::GET, &format!("/api/v2/orgs/{}", id));
+        self.client.send_json(request).await
+    }
+
+    pub async fn update(
+        &self,
+        id: &str,
+        update: &OrganizationUpdate,
+    ) -> Result<Organization, InfluxError> {
+        let request = self
+            .client
+            .api_request(Method::PATCH, &format!("/api/v2/orgs/{}", id))
+            .json(update);
+        self.client.send_json(request).await
+    }
+
+    pub async fn delete(&self, id: &str) -> Result<(), InfluxError> {
+        let request = self
+            .client
+            .api_request(Method::DELETE, &format!("/api/v2/orgs/{}", id));
+        self.client.send_empty(request).await
+    }
+
+    pub async fn members(&self, org_id: &str) -> Result<Vec<Member>, InfluxError> {
+        self.list_role(org_id, Role::Member).await
+    }
+
+    pub async fn add_member(&self, org_id: &str, user_id: &str) -> Result<Member, InfluxError> {
+        self.add_role(org_id, user_id, Role::Member).await
+    }
+
+    pub async fn remove_member(&self, org_id: &str, user_id: &str) -> Result<(), InfluxError> {
+        self.remove_role(org_id, user_id, Role::Member).await
+    }
+
+    pub async fn owners(&self, org_id: &str) -> Result<Vec<Member>, InfluxError> {
+        self.list_role(org_id, Role::Owner).await
+    }
+
+    pub async fn add_owner(&self, org_id: &str, user_id: &str) -> Result<Member, InfluxError> {
+        self.add_role(org_id, user_id, Role::Owner).await
+    }
+
+    pub async fn remove_owner(&self, org_id: &str, user_id: &str) -> Result<(), InfluxError> {
+        self.remove_role(org_id, user_id, Role::Owner).await
+    }
+
+    async fn list_role(&self, org_id: &str, role: Role) -> Result<Vec<Member>, InfluxError> {
+        let request = self
+            .client
+            .api_request(Method::GET, &role_path(org_id, role));
+        let list: MemberList = self.client.send_json(request).await?;
+        Ok(list.users)
+    }
+
+    async fn add_role(
+        &self,
+        org_id: &str,
+        user_id: &str,
+        role: Role,
+    ) -> Result<Member, InfluxError> {
+        let request = self
+            .client
+            .api_request(Method::POST, &role_path(org_id, role))
+            .json(&serde_json::json!({ "id": user_id }));
+        self.client.send_json(request).await
+    }
+
+    async fn remove_role(
+        &self,
+        org_id: &str,
+        user_id: &str,
+        role: Role,
+    ) -> Result<(), InfluxError> {
+        let request = self.client.api_request(
+            Method::DELETE,
+            &format!("{}/{}", role_path(org_id, role), user_id),
+        );
+        self.client.send_empty(request).await
+    }
+}
+
+fn role_path(org_id: &str, role: Role) -> String {
+    match role {
+        Role::Member => format!("/api/v2/orgs/{}/members", org_id),
+        Role::Owner => format!("/api/v2/orgs/{}/owners", org_id),
+    }
+}
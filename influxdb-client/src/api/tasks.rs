@@ -0,0 +1,385 @@
+//! Scheduled Flux tasks, refer: <https://docs.influxdata.com/influxdb/v2.0/api/#tag/Tasks>
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    api::{
+        labels::{self, Label},
+        ListOptions, Status,
+    },
+    client::Client,
+    models::InfluxError,
+};
+
+/// A task as returned by the server.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Task {
+    pub id: String,
+    #[serde(rename = "orgID")]
+    pub org_id: String,
+    pub name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    pub status: Status,
+    /// Flux script of the task, including the `option task` line.
+    pub flux: String,
+    /// Interval the task runs at, e.g. `1h`.
+    #[serde(default)]
+    pub every: Option<String>,
+    /// Cron expression the task runs at.
+    #[serde(default)]
+    pub cron: Option<String>,
+    /// Delay of each run after its scheduled time, e.g. `5m`.
+    #[serde(default)]
+    pub offset: Option<String>,
+    #[serde(default)]
+    pub latest_completed: Option<String>,
+    #[serde(default)]
+    pub last_run_status: Option<String>,
+    #[serde(default)]
+    pub last_run_error: Option<String>,
+    #[serde(default)]
+    pub created_at: Option<String>,
+    #[serde(default)]
+    pub updated_at: Option<String>,
+}
+
+/// When a task runs.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Schedule {
+    /// Interval as Flux duration, e.g. `1h`.
+    Every(String),
+    /// Cron expression, e.g. `0 * * * *`.
+    Cron(String),
+}
+
+/// A task to create.
+///
+/// # Example
+/// ```
+/// use influxdb_client::api::{NewTask, Schedule};
+///
+/// let task = NewTask::new(
+///     "downsample",
+///     Schedule::Every("1h".to_string()),
+///     r#"from(bucket: "raw") |> range(start: -task.every) |> aggregateWindow(every: 1m, fn: mean) |> to(bucket: "downsampled")"#,
+/// )
+/// .with_offset("5m");
+/// ```
+#[derive(Debug, Clone)]
+pub struct NewTask {
+    org_id: Option<String>,
+    name: String,
+    schedule: Schedule,
+    offset: Option<String>,
+    description: Option<String>,
+    status: Option<Status>,
+    flux: String,
+}
+
+impl NewTask {
+    /// An active task in the organization of the client, running `flux` on `schedule`.
+    ///
+    /// `flux` is the script without the `option task` line, which is added from the options.
+    pub fn new(name: impl Into<String>, schedule: Schedule, flux: impl Into<String>) -> Self {
+        NewTask {
+            org_id: None,
+            name: name.into(),
+            schedule,
+            offset: None,
+            description: None,
+            status: None,
+            flux: flux.into(),
+        }
+    }
+
+    /// Create the task in another organization than the one of the client.
+    pub fn with_org_id(mut self, org_id: impl Into<String>) -> Self {
+        self.org_id = Some(org_id.into());
+        self
+    }
+
+    /// Delay each run after its scheduled time, as Flux duration.
+    pub fn with_offset(mut self, offset: impl Into<String>) -> Self {
+        self.offset = Some(offset.into());
+        self
+    }
+
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn with_status(mut self, status: Status) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// The script with the `option task` line.
+    fn script(&self) -> String {
+        let mut options = format!("name: {}", flux_string(&self.name));
+        match &self.schedule {
+            Schedule::Every(every) => options.push_str(&format!(", every: {}", every)),
+            Schedule::Cron(cron) => options.push_str(&format!(", cron: {}", flux_string(cron))),
+        }
+        if let Some(offset) = &self.offset {
+            options.push_str(&format!(", offset: {}", offset));
+        }
+        format!("option task = {{{}}}\n\n{}", options, self.flux)
+    }
+}
+
+/// Quote a Flux string literal.
+fn flux_string(s: &str) -> String {
+    format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Changes to a task. Only the given properties are changed.
+///
+/// Name, schedule and offset are changed in the `option task` line of the script.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct TaskUpdate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    status: Option<Status>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    flux: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    every: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cron: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    offset: Option<String>,
+}
+
+impl TaskUpdate {
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn with_status(mut self, status: Status) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Replace the whole script, including the `option task` line.
+    pub fn with_flux(mut self, flux: impl Into<String>) -> Self {
+        self.flux = Some(flux.into());
+        self
+    }
+
+    /// Replace the schedule, including one of the other kind set before.
+    pub fn with_schedule(mut self, schedule: Schedule) -> Self {
+        match schedule {
+            Schedule::Every(every) => {
+                self.every = Some(every);
+                self.cron = None;
+            }
+            Schedule::Cron(cron) => {
+                self.cron = Some(cron);
+                self.every = None;
+            }
+        }
+        self
+    }
+
+    pub fn with_offset(mut self, offset: impl Into<String>) -> Self {
+        self.offset = Some(offset.into());
+        self
+    }
+}
+
+/// A run of a task.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Run {
+    pub id: String,
+    #[serde(rename = "taskID")]
+    pub task_id: String,
+    pub status: RunStatus,
+    #[serde(default)]
+    pub scheduled_for: Option<String>,
+    #[serde(default)]
+    pub started_at: Option<String>,
+    #[serde(default)]
+    pub finished_at: Option<String>,
+    #[serde(default)]
+    pub requested_at: Option<String>,
+    #[serde(default)]
+    pub log: Vec<LogEvent>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RunStatus {
+    Scheduled,
+    Started,
+    Failed,
+    Success,
+    Canceled,
+}
+
+/// A log line of a task run.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct LogEvent {
+    #[serde(default, rename = "runID")]
+    pub run_id: Option<String>,
+    pub time: String,
+    pub message: String,
+}
+
+#[derive(Serialize)]
+struct TaskCreate<'a> {
+    #[serde(rename = "orgID")]
+    org_id: String,
+    flux: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    status: Option<Status>,
+}
+
+#[derive(Deserialize)]
+struct TaskList {
+    tasks: Vec<Task>,
+}
+
+#[derive(Deserialize)]
+struct RunList {
+    runs: Vec<Run>,
+}
+
+#[derive(Deserialize)]
+struct LogList {
+    events: Vec<LogEvent>,
+}
+
+/// Task API, see [`Client::tasks`].
+pub struct Tasks<'a> {
+    client: &'a Client,
+}
+
+impl Client {
+    /// Create, list, update, delete and run tasks.
+    pub fn tasks(&self) -> Tasks<'_> {
+        Tasks { client: self }
+    }
+}
+
+impl Tasks<'_> {
+    pub async fn create(&self, task: &NewTask) -> Result<Task, InfluxError> {
+        let org_id = match &task.org_id {
+            Some(org_id) => org_id.clone(),
+            None => self.client.resolve_org_id().await?,
+        };
+        let body = TaskCreate {
+            org_id,
+            flux: task.script(),
+            description: task.description.as_deref(),
+            status: task.status,
+        };
+
+        let request = self
+            .client
+            .api_request(Method::POST, "/api/v2/tasks")
+            .json(&body);
+        self.client.send_json(request).await
+    }
+
+    /// List tasks of the organization of the client, or all tasks if it has none.
+    pub async fn list(&self, options: &ListOptions) -> Result<Vec<Task>, InfluxError> {
+        let mut query = options.query("name");
+        if let Some(org_id) = self.client.configured_org_id().await? {
+            query.push(("orgID", org_id));
+        }
+
+        let request = self
+            .client
+            .api_request(Method::GET, "/api/v2/tasks")
+            .query(&query);
+        let list: TaskList = self.client.send_json(request).await?;
+        Ok(list.tasks)
+    }
+
+    pub async fn get(&self, id: &str) -> Result<Task, InfluxError> {
+        let request = self
+            .client
+            .api_request(Method::GET, &format!("/api/v2/tasks/{}", id));
+        self.client.send_json(request).await
+    }
+
+    pub async fn update(&self, id: &str, update: &TaskUpdate) -> Result<Task, InfluxError> {
+        let request = self
+            .client
+            .api_request(Method::PATCH, &format!("/api/v2/tasks/{}", id))
+            .json(update);
+        self.client.send_json(request).await
+    }
+
+    pub async fn delete(&self, id: &str) -> Result<(), InfluxError> {
+        let request = self
+            .client
+            .api_request(Method::DELETE, &format!("/api/v2/tasks/{}", id));
+        self.client.send_empty(request).await
+    }
+
+    /// Start a run of the task now, outside of its schedule.
+    pub async fn run(&self, id: &str) -> Result<Run, InfluxError> {
+        let request = self
+            .client
+            .api_request(Method::POST, &format!("/api/v2/tasks/{}/runs", id))
+            .json(&serde_json::json!({}));
+        self.client.send_json(request).await
+    }
+
+    /// Recent runs of the task.
+    pub async fn runs(&self, id: &str) -> Result<Vec<Run>, InfluxError> {
+        let request = self
+            .client
+            .api_request(Method::GET, &format!("/api/v2/tasks/{}/runs", id));
+        let list: RunList = self.client.send_json(request).await?;
+        Ok(list.runs)
+    }
+
+    /// Logs of all recent runs of the task.
+    pub async fn logs(&self, id: &str) -> Result<Vec<LogEvent>, InfluxError> {
+        let request = self
+            .client
+            .api_request(Method::GET, &format!("/api/v2/tasks/{}/logs", id));
+        let list: LogList = self.client.send_json(request).await?;
+        Ok(list.events)
+    }
+
+    /// Logs of a single run of the task.
+    pub async fn run_logs(&self, id: &str, run_id: &str) -> Result<Vec<LogEvent>, InfluxError> {
+        let request = self.client.api_request(
+            Method::GET,
+            &format!("/api/v2/tasks/{}/runs/{}/logs", id, run_id),
+        );
+        let list: LogList = self.client.send_json(request).await?;
+        Ok(list.events)
+    }
+
+    /// Labels attached to the task.
+    pub async fn labels(&self, id: &str) -> Result<Vec<Label>, InfluxError> {
+        labels::list_labels(self.client, &format!("/api/v2/tasks/{}", id)).await
+    }
+
+    pub async fn add_label(&self, id: &str, label_id: &str) -> Result<Label, InfluxError> {
+        labels::add_label(self.client, &format!("/api/v2/tasks/{}", id), label_id).await
+    }
+
+    pub async fn remove_label(&self, id: &str, label_id: &str) -> Result<(), InfluxError> {
+        labels::remove_label(self.client, &format!("/api/v2/tasks/{}", id), label_id).await
+    }
+}
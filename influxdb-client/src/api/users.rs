@@ -0,0 +1,115 @@
+//! Users, refer: <https://docs.influxdata.com/influxdb/v2.0/api/#tag/Users>
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{api::ListOptions, client::Client, models::InfluxError};
+
+/// Whether a user or token can be used.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Status {
+    Active,
+    Inactive,
+}
+
+/// A user as returned by the server.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct User {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub status: Option<Status>,
+}
+
+/// Changes to a user. Only the given properties are changed.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct UserUpdate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    status: Option<Status>,
+}
+
+impl UserUpdate {
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    pub fn with_status(mut self, status: Status) -> Self {
+        self.status = Some(status);
+        self
+    }
+}
+
+#[derive(Deserialize)]
+struct UserList {
+    users: Vec<User>,
+}
+
+/// User API, see [`Client::users`].
+pub struct Users<'a> {
+    client: &'a Client,
+}
+
+impl Client {
+    /// Create, list, update and delete users.
+    pub fn users(&self) -> Users<'_> {
+        Users { client: self }
+    }
+}
+
+impl Users<'_> {
+    pub async fn create(&self, name: &str) -> Result<User, InfluxError> {
+        let request = self
+            .client
+            .api_request(Method::POST, "/api/v2/users")
+            .json(&serde_json::json!({ "name": name }));
+        self.client.send_json(request).await
+    }
+
+    pub async fn list(&self, options: &ListOptions) -> Result<Vec<User>, InfluxError> {
+        let request = self
+            .client
+            .api_request(Method::GET, "/api/v2/users")
+            .query(&options.query("name"));
+        let list: UserList = self.client.send_json(request).await?;
+        Ok(list.users)
+    }
+
+    pub async fn get(&self, id: &str) -> Result<User, InfluxError> {
+        let request = self
+            .client
+            .api_request(Method::GET, &format!("/api/v2/users/{}", id));
+        self.client.send_json(request).await
+    }
+
+    /// The user owning the token of the client.
+    pub async fn me(&self) -> Result<User, InfluxError> {
+        let request = self.client.api_request(Method::GET, "/api/v2/me");
+        self.client.send_json(request).await
+    }
+
+    pub async fn update(&self, id: &str, update: &UserUpdate) -> Result<User, InfluxError> {
+        let request = self
+            .client
+            .api_request(Method::PATCH, &format!("/api/v2/users/{}", id))
+            .json(update);
+        self.client.send_json(request).await
+    }
+
+    pub async fn set_password(&self, id: &str, password: &str) -> Result<(), InfluxError> {
+        let request = self
+            .client
+            .api_request(Method::POST, &format!("/api/v2/users/{}/password", id))
+            .json(&serde_json::json!({ "password": password }));
+        self.client.send_empty(request).await
+    }
+
+    pub async fn delete(&self, id: &str) -> Result<(), InfluxError> {
+        let request = self
+            .client
+            .api_request(Method::DELETE, &format!("/api/v2/users/{}", id));
+        self.client.send_empty(request).await
+    }
+}
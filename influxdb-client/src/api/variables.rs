@@ -0,0 +1,158 @@
+//! Dashboard variables, refer: <https://docs.influxdata.com/influxdb/v2.0/api/#tag/Variables>
+use std::collections::HashMap;
+
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{client::Client, models::InfluxError};
+
+/// A variable, as created or returned by the server.
+///
+/// # Example
+/// ```
+/// use influxdb_client::api::{Variable, VariableArguments};
+///
+/// let variable = Variable::new(
+///     "host",
+///     VariableArguments::query(r#"import "influxdata/influxdb/schema" schema.tagValues(bucket: "telegraf", tag: "host")"#),
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Variable {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The organization of the client if not set.
+    #[serde(default, rename = "orgID", skip_serializing_if = "Option::is_none")]
+    pub org_id: Option<String>,
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Values selected by default.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub selected: Vec<String>,
+    pub arguments: VariableArguments,
+}
+
+impl Variable {
+    pub fn new(name: impl Into<String>, arguments: VariableArguments) -> Self {
+        Variable {
+            id: None,
+            org_id: None,
+            name: name.into(),
+            description: None,
+            selected: Vec::new(),
+            arguments,
+        }
+    }
+
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Select this value by default.
+    pub fn with_selected(mut self, value: impl Into<String>) -> Self {
+        self.selected.push(value.into());
+        self
+    }
+}
+
+/// Where the values of a variable come from.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "values", rename_all = "lowercase")]
+pub enum VariableArguments {
+    /// A fixed list of values.
+    Constant(Vec<String>),
+    /// Values shown to the user by key.
+    Map(HashMap<String, String>),
+    /// Values returned by a query.
+    Query(VariableQuery),
+}
+
+impl VariableArguments {
+    /// Values returned by a Flux query.
+    pub fn query(flux: impl Into<String>) -> Self {
+        VariableArguments::Query(VariableQuery {
+            query: flux.into(),
+            language: "flux".to_string(),
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VariableQuery {
+    pub query: String,
+    pub language: String,
+}
+
+#[derive(Deserialize)]
+struct VariableList {
+    variables: Vec<Variable>,
+}
+
+/// Variable API, see [`Client::variables`].
+pub struct Variables<'a> {
+    client: &'a Client,
+}
+
+impl Client {
+    /// Create, list, update and delete dashboard variables.
+    pub fn variables(&self) -> Variables<'_> {
+        Variables { client: self }
+    }
+}
+
+impl Variables<'_> {
+    pub async fn create(&self, variable: &Variable) -> Result<Variable, InfluxError> {
+        let mut variable = variable.clone();
+        if variable.org_id.is_none() {
+            variable.org_id = Some(self.client.resolve_org_id().await?);
+        }
+
+        let request = self
+            .client
+            .api_request(Method::POST, "/api/v2/variables")
+            .json(&variable);
+        self.client.send_json(request).await
+    }
+
+    /// List variables of the organization of the client.
+    ///
+    /// The server cannot filter variables by name or page them.
+    pub async fn list(&self) -> Result<Vec<Variable>, InfluxError> {
+        let request = self
+            .client
+            .api_request(Method::GET, "/api/v2/variables")
+            .query(&[("orgID", self.client.resolve_org_id().await?)]);
+        let list: VariableList = self.client.send_json(request).await?;
+        Ok(list.variables)
+    }
+
+    pub async fn get(&self, id: &str) -> Result<Variable, InfluxError> {
+        let request = self
+            .client
+            .api_request(Method::GET, &format!("/api/v2/variables/{}", id));
+        self.client.send_json(request).await
+    }
+
+    /// Replace the variable with `variable`.
+    pub async fn update(&self, id: &str, variable: &Variable) -> Result<Variable, InfluxError> {
+        let mut variable = variable.clone();
+        if variable.org_id.is_none() {
+            variable.org_id = Some(self.client.resolve_org_id().await?);
+        }
+
+        let request = self
+            .client
+            .api_request(Method::PUT, &format!("/api/v2/variables/{}", id))
+            .json(&variable);
+        self.client.send_json(request).await
+    }
+
+    pub async fn delete(&self, id: &str) -> Result<(), InfluxError> {
+        let request = self
+            .client
+            .api_request(Method::DELETE, &format!("/api/v2/variables/{}", id));
+        self.client.send_empty(request).await
+    }
+}
@@ -0,0 +1,307 @@
+//! Buffer points in the background and write them in batches.
+use std::collections::VecDeque;
+use std::time::Duration;
+
+use tokio::sync::{mpsc, oneshot};
+
+use crate::{
+    client::Client,
+    models::{InfluxError, Precision, TimestampOptions},
+    traits::PointSerialize,
+};
+
+/// When a [`BatchWriter`] flushes its buffer.
+///
+/// A flush happens when any of the thresholds is hit.
+#[derive(Clone)]
+pub struct BatchOptions {
+    max_lines: usize,
+    max_bytes: usize,
+    flush_interval: Duration,
+    channel_capacity: usize,
+    timestamp: TimestampOptions,
+}
+
+impl Default for BatchOptions {
+    fn default() -> Self {
+        BatchOptions {
+            max_lines: 5000,
+            max_bytes: 1024 * 1024,
+            flush_interval: Duration::from_secs(1),
+            channel_capacity: 1024,
+            timestamp: TimestampOptions::FromPoint,
+        }
+    }
+}
+
+impl BatchOptions {
+    /// Flush when the buffer holds this many lines. Defaults to 5000.
+    ///
+    /// While a failed batch is kept for retrying, the oldest lines beyond this are dropped.
+    pub fn with_max_lines(mut self, max_lines: usize) -> Self {
+        self.max_lines = max_lines;
+        self
+    }
+
+    /// Flush when the buffer holds this many bytes. Defaults to 1 MiB.
+    ///
+    /// While a failed batch is kept for retrying, the oldest lines beyond this are dropped.
+    pub fn with_max_bytes(mut self, max_bytes: usize) -> Self {
+        self.max_bytes = max_bytes;
+        self
+    }
+
+    /// Flush at least this often. Defaults to 1 second, intervals below 1 millisecond are raised to it.
+    pub fn with_flush_interval(mut self, flush_interval: Duration) -> Self {
+        self.flush_interval = flush_interval.max(Duration::from_millis(1));
+        self
+    }
+
+    /// Number of points that can be queued before [`BatchWriter::write`] waits. Defaults to 1024.
+    pub fn with_channel_capacity(mut self, channel_capacity: usize) -> Self {
+        self.channel_capacity = channel_capacity;
+        self
+    }
+
+    /// How timestamps of written points are serialized. Defaults to [`TimestampOptions::FromPoint`].
+    pub fn with_timestamp(mut self, timestamp: TimestampOptions) -> Self {
+        self.timestamp = timestamp;
+        self
+    }
+}
+
+enum Command {
+    Line(String),
+    Flush(oneshot::Sender<Result<(), InfluxError>>),
+    Close(oneshot::Sender<Result<(), InfluxError>>),
+}
+
+/// Writes points in batches from a background task.
+///
+/// The writer can be cloned and shared between tasks; all clones feed the same buffer.
+/// Errors of automatic flushes are returned by the next call to [`BatchWriter::flush`]
+/// or [`BatchWriter::close`], as [`InfluxError::WritesFailed`] if more than one batch failed.
+///
+/// Batches that fail with a retryable error, see [`InfluxError::is_retryable`], are kept
+/// and written again with the next flush interval or explicit flush. Other failed batches
+/// are dropped. While a batch is kept, the buffer drops its oldest lines to stay within
+/// the line and byte limits, reported as [`InfluxError::LinesDropped`].
+///
+/// # Example
+/// ```no_run
+/// # async fn example() -> Result<(), influxdb_client::InfluxError> {
+/// use std::time::Duration;
+/// use influxdb_client::{BatchOptions, BatchWriter, Client, Point};
+///
+/// let client = Client::new("http://localhost:8086", "token").unwrap().with_bucket("tradely");
+/// let writer = BatchWriter::new(
+///     client,
+///     BatchOptions::default().with_flush_interval(Duration::from_secs(5)),
+/// );
+///
+/// writer.write(&Point::new("test").field("price", 420.69)).await?;
+/// writer.close().await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct BatchWriter {
+    sender: mpsc::Sender<Command>,
+    timestamp: TimestampOptions,
+    precision: Precision,
+    strict_timestamps: bool,
+}
+
+impl BatchWriter {
+    /// Spawn the background task of the writer. Must be called from within a tokio runtime.
+    pub fn new(client: Client, options: BatchOptions) -> Self {
+        let (sender, receiver) = mpsc::channel(options.channel_capacity.max(1));
+        let timestamp = options.timestamp.clone();
+        let precision = client.precision;
+        let strict_timestamps = client.strict_timestamps;
+        tokio::spawn(run(client, options, receiver));
+        BatchWriter {
+            sender,
+            timestamp,
+            precision,
+            strict_timestamps,
+        }
+    }
+
+    /// Queue a point for writing.
+    pub async fn write(&self, point: &impl PointSerialize) -> Result<(), InfluxError> {
+        point.validate()?;
+        let line = self
+            .timestamp
+            .serialize(point, self.precision, self.strict_timestamps)?;
+        self.send(Command::Line(line)).await
+    }
+
+    /// Write everything queued so far.
+    pub async fn flush(&self) -> Result<(), InfluxError> {
+        let (reply, result) = oneshot::channel();
+        self.send(Command::Flush(reply)).await?;
+        result.await.map_err(|_| InfluxError::WriterClosed)?
+    }
+
+    /// Write everything queued so far and stop the background task.
+    ///
+    /// Writes through other clones of this writer fail after closing.
+    pub async fn close(self) -> Result<(), InfluxError> {
+        let (reply, result) = oneshot::channel();
+        self.send(Command::Close(reply)).await?;
+        result.await.map_err(|_| InfluxError::WriterClosed)?
+    }
+
+    async fn send(&self, command: Command) -> Result<(), InfluxError> {
+        self.sender
+            .send(command)
+            .await
+            .map_err(|_| InfluxError::WriterClosed)
+    }
+}
+
+async fn run(client: Client, options: BatchOptions, mut receiver: mpsc::Receiver<Command>) {
+    let mut buffer = Buffer::default();
+    let mut errors = Errors::default();
+
+    let mut interval = tokio::time::interval(options.flush_interval);
+    // The first tick completes immediately
+    interval.tick().await;
+
+    loop {
+        tokio::select! {
+            command = receiver.recv() => match command {
+                Some(Command::Line(line)) => {
+                    buffer.push(line, &options);
+                    // A failed batch is retried with the next tick rather than with every line
+                    if !buffer.failed && buffer.is_full(&options) {
+                        if let Err(e) = buffer.flush(&client).await {
+                            errors.push(e);
+                        }
+                        interval.reset();
+                    }
+                }
+                Some(Command::Flush(reply)) => {
+                    if let Err(e) = buffer.flush(&client).await {
+                        errors.push(e);
+                    }
+                    let _ = reply.send(errors.take(&mut buffer));
+                }
+                Some(Command::Close(reply)) => {
+                    if let Err(e) = buffer.flush(&client).await {
+                        errors.push(e);
+                    }
+                    let _ = reply.send(errors.take(&mut buffer));
+                    return;
+                }
+                None => {
+                    // All writers are dropped without closing
+                    let _ = buffer.flush(&client).await;
+                    return;
+                }
+            },
+            _ = interval.tick() => {
+                if let Err(e) = buffer.flush(&client).await {
+                    errors.push(e);
+                }
+            }
+        }
+    }
+}
+
+/// Errors since the last flush or close, only keeping the first and the last one.
+#[derive(Default)]
+struct Errors {
+    count: usize,
+    first: Option<InfluxError>,
+    last: Option<InfluxError>,
+}
+
+impl Errors {
+    fn push(&mut self, error: InfluxError) {
+        self.count += 1;
+        if self.first.is_none() {
+            self.first = Some(error);
+        } else {
+            self.last = Some(error);
+        }
+    }
+
+    /// Take the errors, including lines dropped by `buffer`, combined if there are several.
+    fn take(&mut self, buffer: &mut Buffer) -> Result<(), InfluxError> {
+        let dropped = std::mem::take(&mut buffer.dropped);
+        if dropped > 0 {
+            self.push(InfluxError::LinesDropped(dropped));
+        }
+
+        let errors = std::mem::take(self);
+        match (errors.first, errors.last) {
+            (None, _) => Ok(()),
+            (Some(first), None) => Err(first),
+            (Some(first), Some(last)) => Err(InfluxError::WritesFailed {
+                count: errors.count,
+                first: Box::new(first),
+                last: Box::new(last),
+            }),
+        }
+    }
+}
+
+#[derive(Default)]
+struct Buffer {
+    lines: VecDeque<String>,
+    /// Size of the body, counting a newline after every line.
+    bytes: usize,
+    /// Whether the lines start with a batch that failed and is kept for retrying.
+    failed: bool,
+    /// Lines dropped since the last report.
+    dropped: usize,
+}
+
+impl Buffer {
+    /// Queue `line`. While a failed batch is kept, the oldest lines beyond the limits of
+    /// `options` are dropped, otherwise the buffer is flushed once it is full.
+    fn push(&mut self, line: String, options: &BatchOptions) {
+        self.bytes += line.len() + 1;
+        self.lines.push_back(line);
+        while self.failed
+            && self.lines.len() > 1
+            && (self.lines.len() > options.max_lines || self.bytes > options.max_bytes)
+        {
+            if let Some(oldest) = self.lines.pop_front() {
+                self.bytes -= oldest.len() + 1;
+                self.dropped += 1;
+            }
+        }
+    }
+
+    fn is_full(&self, options: &BatchOptions) -> bool {
+        self.lines.len() >= options.max_lines || self.bytes >= options.max_bytes
+    }
+
+    async fn flush(&mut self, client: &Client) -> Result<(), InfluxError> {
+        if self.lines.is_empty() {
+            return Ok(());
+        }
+        let body = self
+            .lines
+            .iter()
+            .map(String::as_str)
+            .collect::<Vec<_>>()
+            .join("\n");
+        let result = client.write_body(body).await;
+
+        let retryable = match &result {
+            Err(InfluxError::RetriesExhausted { source, .. }) => source.is_retryable(),
+            Err(e) => e.is_retryable(),
+            Ok(()) => false,
+        };
+        if !retryable {
+            self.lines.clear();
+            self.bytes = 0;
+        }
+        self.failed = retryable;
+        result
+    }
+}
@@ -1,8 +1,15 @@
-use reqwest::{Client as HttpClient, Method, StatusCode, Url};
+use std::convert::TryFrom;
+use std::sync::{Arc, Mutex};
+
+use futures::{Stream, TryStreamExt};
+use reqwest::{Client as HttpClient, Method, Response, Url};
+use serde::de::DeserializeOwned;
 
 use crate::{
-    models::{InfluxError, Precision, TimestampOptions},
-    traits::PointSerialize,
+    csv::{self, CsvError, Record},
+    models::{ApiError, InfluxError, Precision, TimestampOptions},
+    retry::RetryPolicy,
+    traits::{FromRecord, PointSerialize},
 };
 
 /// Client for InfluxDB.
@@ -17,7 +24,13 @@ pub struct Client {
     bucket: Option<String>,
     org: Option<String>,
     org_id: Option<String>,
-    precision: Precision,
+    /// ID of `org`, shared between clones once looked up.
+    resolved_org_id: Arc<Mutex<Option<String>>>,
+    pub(crate) precision: Precision,
+    pub(crate) strict_timestamps: bool,
+    retry_policy: Option<RetryPolicy>,
+    #[cfg(feature = "gzip")]
+    gzip: bool,
 
     insert_to_stdout: bool,
 }
@@ -43,7 +56,12 @@ impl Client {
             bucket: None,
             org: None,
             org_id: None,
+            resolved_org_id: Arc::default(),
             precision: Precision::NS,
+            strict_timestamps: false,
+            retry_policy: None,
+            #[cfg(feature = "gzip")]
+            gzip: false,
             insert_to_stdout: false,
         })
     }
@@ -54,6 +72,12 @@ impl Client {
         self
     }
 
+    /// Use another token, e.g. one created during setup.
+    pub(crate) fn with_token(mut self, token: impl Into<String>) -> Self {
+        self.token = token.into();
+        self
+    }
+
     pub fn with_bucket<T: Into<String>>(mut self, bucket: T) -> Self {
         self.bucket = Some(bucket.into());
         self
@@ -61,6 +85,7 @@ impl Client {
 
     pub fn with_org<T: Into<String>>(mut self, org: T) -> Self {
         self.org = Some(org.into());
+        self.resolved_org_id = Arc::default();
         self
     }
 
@@ -69,11 +94,35 @@ impl Client {
         self
     }
 
+    /// Precision of written timestamps. Timestamps that carry their own unit
+    /// ([`crate::Timestamp::Precise`]) are rescaled to it. Defaults to nanoseconds.
     pub fn with_precision(mut self, precision: Precision) -> Self {
         self.precision = precision;
         self
     }
 
+    /// Fail writes of timestamps that would be rounded when rescaled to the precision
+    /// of the client, instead of rounding them down. Defaults to false.
+    pub fn with_strict_timestamps(mut self, strict: bool) -> Self {
+        self.strict_timestamps = strict;
+        self
+    }
+
+    /// Retry failed writes according to `policy`. By default writes are not retried.
+    pub fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(policy);
+        self
+    }
+
+    /// Compress written line protocol with gzip. Disabled by default.
+    ///
+    /// Gzip-encoded query responses are always decoded when the `gzip` feature is enabled.
+    #[cfg(feature = "gzip")]
+    pub fn with_gzip(mut self, gzip: bool) -> Self {
+        self.gzip = gzip;
+        self
+    }
+
     pub fn reqwest_client(mut self, client: reqwest::Client) -> Self {
         self.client = client;
         self
@@ -88,57 +137,201 @@ impl Client {
         points: I,
         options: TimestampOptions,
     ) -> Result<(), InfluxError> {
-        let body = points
+        let body = self.serialize_points(points, &options)?;
+        self.write_body(body).await
+    }
+
+    /// Validate and serialize points into line protocol in the precision of the client.
+    pub(crate) fn serialize_points<'a, I: IntoIterator<Item = &'a (impl PointSerialize + 'a)>>(
+        &self,
+        points: I,
+        options: &TimestampOptions,
+    ) -> Result<String, InfluxError> {
+        Ok(points
             .into_iter()
-            .map(|p| match options.clone() {
-                TimestampOptions::Use(t) => p.serialize_with_timestamp(Some(t)),
-                TimestampOptions::FromPoint => p.serialize_with_timestamp(None),
-                TimestampOptions::None => p.serialize(),
+            .map(|p| {
+                p.validate()?;
+                options.serialize(p, self.precision, self.strict_timestamps)
             })
-            .collect::<Vec<String>>()
-            .join("\n");
+            .collect::<Result<Vec<String>, InfluxError>>()?
+            .join("\n"))
+    }
 
+    /// Write already serialized line protocol.
+    pub(crate) async fn write_body(&self, body: String) -> Result<(), InfluxError> {
         let precision = self.precision.to_string();
-        let write_query_params = [("precision", precision)];
+        self.write_body_with(body, || {
+            self.new_request(Method::POST, "/api/v2/write")
+                .query(&[("precision", precision)])
+        })
+        .await
+    }
 
+    /// Write already serialized line protocol with requests built by `request`,
+    /// compressing and retrying like other writes.
+    pub(crate) async fn write_body_with(
+        &self,
+        body: String,
+        request: impl Fn() -> reqwest::RequestBuilder,
+    ) -> Result<(), InfluxError> {
         if self.insert_to_stdout {
             println!("{}", body);
-            Ok(())
-        } else {
-            let response = self
-                .new_request(Method::POST, "/api/v2/write")
-                .query(&write_query_params)
-                .body(body)
-                .send()
-                .await?;
-
-            match response.status() {
-                StatusCode::BAD_REQUEST => {
-                    let content = response.text().await?;
-                    Err(InfluxError::InvalidSyntax(content))
-                }
-                StatusCode::UNAUTHORIZED => {
-                    let content = response.text().await?;
-                    Err(InfluxError::InvalidCredentials(content))
-                }
-                StatusCode::FORBIDDEN => {
-                    let content = response.text().await?;
-                    Err(InfluxError::Forbidden(content))
-                }
-                s if matches!(s.as_u16(), 400..=499 | 500..=500) => {
-                    let content = response.text().await?;
-                    Err(InfluxError::Unknown(content))
-                }
-                _ => {
-                    // this is the hot path, no need to wait for body here,
-                    // drop the response immediately for better performance.
-                    Ok(())
-                }
+            return Ok(());
+        }
+
+        #[cfg(feature = "gzip")]
+        let (body, encoding) = match self.gzip {
+            true => (gzip(body.as_bytes()), Some("gzip")),
+            false => (body.into_bytes(), None),
+        };
+        #[cfg(not(feature = "gzip"))]
+        let (body, encoding) = (body.into_bytes(), None);
+
+        match &self.retry_policy {
+            Some(policy) => {
+                policy
+                    .retry(|| send_write(request(), body.clone(), encoding))
+                    .await
             }
+            None => send_write(request(), body, encoding).await,
         }
     }
 
-    fn new_request(&self, method: Method, path: &str) -> reqwest::RequestBuilder {
+    /// Run a Flux query and convert all records of the result.
+    ///
+    /// Use [`Record`] as `T` to get the records as they are.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # async fn example() -> Result<(), influxdb_client::InfluxError> {
+    /// use influxdb_client::{Client, Record};
+    /// let client = Client::new("http://localhost:8086", "token").unwrap().with_org("org");
+    /// let records = client
+    ///     .query::<Record>("from(bucket: \"tradely\") |> range(start: -1h)")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn query<T: FromRecord>(&self, flux: &str) -> Result<Vec<T>, InfluxError> {
+        self.query_stream(flux)
+            .await?
+            .and_then(|record| async { Ok(T::from_record(record)?) })
+            .try_collect()
+            .await
+    }
+
+    /// Run a Flux query and stream the records while the response is downloading.
+    ///
+    /// Errors that occur mid-stream, including Flux errors reported in the
+    /// response body, are yielded as items of the stream.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # async fn example() -> Result<(), influxdb_client::InfluxError> {
+    /// use futures::TryStreamExt;
+    /// use influxdb_client::Client;
+    /// let client = Client::new("http://localhost:8086", "token").unwrap().with_org("org");
+    /// let mut records = client
+    ///     .query_stream("from(bucket: \"tradely\") |> range(start: -1h)")
+    ///     .await?;
+    /// while let Some(record) = records.try_next().await? {
+    ///     println!("{:?}", record.value());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn query_stream(
+        &self,
+        flux: &str,
+    ) -> Result<impl Stream<Item = Result<Record, InfluxError>> + Unpin, InfluxError> {
+        let body = serde_json::json!({
+            "query": flux,
+            "type": "flux",
+            "dialect": {
+                "annotations": ["datatype", "group", "default"],
+            },
+        });
+
+        let response = self
+            .new_request(Method::POST, "/api/v2/query")
+            .header("Accept", "application/csv")
+            .json(&body)
+            .send()
+            .await?;
+        let body = check_status(response)
+            .await?
+            .bytes_stream()
+            .map_err(std::io::Error::other)
+            .into_async_read();
+
+        Ok(Box::pin(
+            csv::read_records_async(body).map_err(stream_error),
+        ))
+    }
+
+    /// ID of the configured organization.
+    ///
+    /// If only the name of the organization is configured with [`Client::with_org`], the ID is
+    /// looked up once and shared by all clones of this client.
+    pub async fn resolve_org_id(&self) -> Result<String, InfluxError> {
+        if let Some(org_id) = &self.org_id {
+            return Ok(org_id.clone());
+        }
+        let org = self.org.as_ref().ok_or(InfluxError::MissingOrg)?;
+        let cached = self.resolved_org_id.lock().unwrap().clone();
+        if let Some(org_id) = cached {
+            return Ok(org_id);
+        }
+
+        let org_id = self
+            .orgs()
+            .find_by_name(org)
+            .await?
+            .ok_or(InfluxError::MissingOrg)?
+            .id;
+        *self.resolved_org_id.lock().unwrap() = Some(org_id.clone());
+        Ok(org_id)
+    }
+
+    /// Like [`Client::resolve_org_id`], but `None` if no organization is configured.
+    ///
+    /// For APIs that list the resources of all organizations without one. A configured
+    /// organization that cannot be looked up still fails.
+    pub(crate) async fn configured_org_id(&self) -> Result<Option<String>, InfluxError> {
+        if self.org_id.is_none() && self.org.is_none() {
+            return Ok(None);
+        }
+        self.resolve_org_id().await.map(Some)
+    }
+
+    /// Request to the management API, without the bucket and organization of writes.
+    pub(crate) fn api_request(&self, method: Method, path: &str) -> reqwest::RequestBuilder {
+        let mut url = self.host.clone();
+        url.set_path(path);
+
+        self.client
+            .request(method, url)
+            .header("Authorization", format!("Token {}", self.token))
+    }
+
+    /// Send a request and decode the JSON body of the response.
+    pub(crate) async fn send_json<T: DeserializeOwned>(
+        &self,
+        request: reqwest::RequestBuilder,
+    ) -> Result<T, InfluxError> {
+        let response = check_status(request.send().await?).await?;
+        Ok(response.json().await?)
+    }
+
+    /// Send a request whose response has no body of interest.
+    pub(crate) async fn send_empty(
+        &self,
+        request: reqwest::RequestBuilder,
+    ) -> Result<(), InfluxError> {
+        check_status(request.send().await?).await.map(drop)
+    }
+
+    pub(crate) fn new_request(&self, method: Method, path: &str) -> reqwest::RequestBuilder {
         // Build query params
         let mut query_params = Vec::<(&str, String)>::new();
         if let Some(bucket) = &self.bucket {
@@ -157,8 +350,102 @@ impl Client {
 
         self.client
             .request(method, url)
-            .header("Content-Type", "text/plain")
             .header("Authorization", format!("Token {}", self.token))
             .query(&query_params)
     }
 }
+
+/// Compress a request body with gzip.
+#[cfg(feature = "gzip")]
+fn gzip(body: &[u8]) -> Vec<u8> {
+    use std::io::Write;
+
+    let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+    // writing to a Vec does not fail
+    encoder.write_all(body).unwrap();
+    encoder.finish().unwrap()
+}
+
+async fn send_write(
+    request: reqwest::RequestBuilder,
+    body: Vec<u8>,
+    encoding: Option<&str>,
+) -> Result<(), InfluxError> {
+    let mut request = request.header("Content-Type", "text/plain");
+    if let Some(encoding) = encoding {
+        request = request.header("Content-Encoding", encoding);
+    }
+    let response = request.body(body).send().await?;
+
+    // this is the hot path, no need to wait for body here,
+    // drop the response immediately for better performance.
+    check_status(response).await.map(drop)
+}
+
+/// Map non-success status codes onto [`InfluxError`].
+pub(crate) async fn check_status(response: Response) -> Result<Response, InfluxError> {
+    let status = response.status();
+    if status.is_success() {
+        return Ok(response);
+    }
+
+    let retry_after = retry_after(&response);
+    let content = response.text().await?;
+    Err(InfluxError::from_response(ApiError {
+        retry_after,
+        ..ApiError::new(status.as_u16(), content)
+    }))
+}
+
+/// Parse the `Retry-After` header, given in seconds or as HTTP-date.
+fn retry_after(response: &Response) -> Option<std::time::Duration> {
+    let value = response
+        .headers()
+        .get(reqwest::header::RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim();
+    if let Ok(seconds) = value.parse() {
+        return Some(std::time::Duration::from_secs(seconds));
+    }
+
+    // A date in the past means to retry right away
+    let at = parse_http_date(value)?;
+    Some(
+        at.duration_since(std::time::SystemTime::now())
+            .unwrap_or_default(),
+    )
+}
+
+/// Parse an HTTP-date in the preferred format, e.g. `Wed, 21 Oct 2015 07:28:00 GMT`.
+fn parse_http_date(value: &str) -> Option<std::time::SystemTime> {
+    const MONTHS: [&str; 12] = [
+        "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+    ];
+
+    let parts: Vec<&str> = value.split_whitespace().collect();
+    let (day, month, year, time) = match parts[..] {
+        [_, day, month, year, time, "GMT"] => (day, month, year, time),
+        _ => return None,
+    };
+    let month = MONTHS.iter().position(|m| *m == month)? + 1;
+    let rfc3339 = format!("{}-{:02}-{}T{}Z", year, month, day, time);
+    let nanos = u64::try_from(csv::parse_rfc3339(&rfc3339)?).ok()?;
+    Some(std::time::UNIX_EPOCH + std::time::Duration::from_nanos(nanos))
+}
+
+/// Map errors of a streamed response, recovering network errors from the body stream.
+fn stream_error(error: CsvError) -> InfluxError {
+    match error {
+        CsvError::Io(e) if e.get_ref().is_some_and(|e| e.is::<reqwest::Error>()) => {
+            // checked by the guard above
+            let e = e
+                .into_inner()
+                .unwrap()
+                .downcast::<reqwest::Error>()
+                .unwrap();
+            InfluxError::Network(*e)
+        }
+        e => e.into(),
+    }
+}
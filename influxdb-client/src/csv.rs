@@ -0,0 +1,733 @@
+//! Parse annotated CSV returned by the Flux query API.
+//!
+//! The parser works on any [`Read`] or [`AsyncRead`] source, so saved query
+//! results can be parsed the same way as live responses.
+//!
+//! refer: <https://docs.influxdata.com/influxdb/v2.0/reference/syntax/annotated-csv/>
+//!
+//! # Example
+//! ```
+//! use influxdb_client::csv;
+//!
+//! let text = "#datatype,string,long,double\n#group,false,false,false\n#default,_result,,\n,result,table,_value\n,,0,1.5\n";
+//! let tables = csv::read_tables(text.as_bytes()).unwrap();
+//! assert_eq!(tables[0].records.len(), 1);
+//! ```
+use std::convert::TryFrom;
+use std::io::{BufRead, BufReader, Read};
+
+use futures::{
+    io::{AsyncBufReadExt, AsyncRead, BufReader as AsyncBufReader},
+    Stream, StreamExt, TryStreamExt,
+};
+
+use crate::models::{Precision, Timestamp, Value};
+use crate::traits::{FromCell, FromRecord};
+
+/// A single cell of a query result.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Cell {
+    Null,
+    Value(Value),
+    /// Nanoseconds since the unix epoch.
+    Time(Timestamp),
+}
+
+/// A single row of a query result.
+///
+/// Values are kept in the same order as the columns of the table they belong to.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Record {
+    pub values: Vec<(String, Cell)>,
+}
+
+impl Record {
+    /// Get the cell of the given column, if the column exists.
+    pub fn get(&self, column: &str) -> Option<&Cell> {
+        self.values
+            .iter()
+            .find(|(name, _)| name == column)
+            .map(|(_, cell)| cell)
+    }
+
+    /// Get the value of the given column, if the column exists and is not null.
+    pub fn value_of(&self, column: &str) -> Option<&Value> {
+        match self.get(column) {
+            Some(Cell::Value(v)) => Some(v),
+            _ => None,
+        }
+    }
+
+    /// The `result` column.
+    pub fn result(&self) -> Option<&str> {
+        self.str_of("result")
+    }
+
+    /// The `table` column.
+    pub fn table(&self) -> Option<i64> {
+        match self.value_of("table") {
+            Some(Value::Int(i)) => Some(*i),
+            _ => None,
+        }
+    }
+
+    /// The `_time` column.
+    pub fn time(&self) -> Option<&Timestamp> {
+        match self.get("_time") {
+            Some(Cell::Time(t)) => Some(t),
+            _ => None,
+        }
+    }
+
+    /// The `_measurement` column.
+    pub fn measurement(&self) -> Option<&str> {
+        self.str_of("_measurement")
+    }
+
+    /// The `_field` column.
+    pub fn field(&self) -> Option<&str> {
+        self.str_of("_field")
+    }
+
+    /// The `_value` column.
+    pub fn value(&self) -> Option<&Value> {
+        self.value_of("_value")
+    }
+
+    /// Convert the cell of the given column.
+    pub fn decode<T: FromCell>(&self, column: &str) -> Result<T, RecordError> {
+        match self.get(column) {
+            Some(cell) => T::from_cell(cell).ok_or_else(|| RecordError::TypeMismatch {
+                column: column.to_string(),
+                expected: std::any::type_name::<T>(),
+                found: cell.clone(),
+            }),
+            None => T::from_missing().ok_or_else(|| RecordError::MissingColumn(column.to_string())),
+        }
+    }
+
+    /// Convert the value of the given field.
+    ///
+    /// Works for both pivoted records, where the field is a column of its own,
+    /// and unpivoted records, where the field is named in `_field` and the value is in `_value`.
+    pub fn decode_field<T: FromCell>(&self, field: &str) -> Result<T, RecordError> {
+        if self.get(field).is_none() && self.field() == Some(field) {
+            self.decode("_value")
+        } else {
+            self.decode(field)
+        }
+    }
+
+    /// Convert the time in the given column, reading it as nanoseconds for integer types.
+    ///
+    /// Used for `#[point(timestamp)]` fields of the derive, which may be `i64` or `u64`.
+    pub fn decode_time<T: FromCell>(&self, column: &str) -> Result<T, RecordError> {
+        let result = self.decode(column);
+        if result.is_ok() {
+            return result;
+        }
+        let nanos = match self.get(column) {
+            Some(Cell::Time(t)) => t.nanos(Precision::NS).and_then(|n| i64::try_from(n).ok()),
+            _ => None,
+        };
+        match nanos.and_then(|n| T::from_cell(&Cell::Value(Value::Int(n)))) {
+            Some(value) => Ok(value),
+            None => result,
+        }
+    }
+
+    fn str_of(&self, column: &str) -> Option<&str> {
+        match self.value_of(column) {
+            Some(Value::Str(s)) => Some(s),
+            _ => None,
+        }
+    }
+}
+
+impl FromRecord for Record {
+    fn from_record(record: Record) -> Result<Self, RecordError> {
+        Ok(record)
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum RecordError {
+    #[error("Missing column '{0}'")]
+    MissingColumn(String),
+    #[error("Column '{column}' has {found:?}, expected {expected}")]
+    TypeMismatch {
+        column: String,
+        expected: &'static str,
+        found: Cell,
+    },
+}
+
+impl FromCell for Cell {
+    fn from_cell(cell: &Cell) -> Option<Self> {
+        Some(cell.clone())
+    }
+}
+
+impl FromCell for Value {
+    fn from_cell(cell: &Cell) -> Option<Self> {
+        match cell {
+            Cell::Value(v) => Some(v.clone()),
+            _ => None,
+        }
+    }
+}
+
+impl FromCell for String {
+    fn from_cell(cell: &Cell) -> Option<Self> {
+        match cell {
+            Cell::Value(Value::Str(s)) => Some(s.clone()),
+            _ => None,
+        }
+    }
+}
+
+impl FromCell for bool {
+    fn from_cell(cell: &Cell) -> Option<Self> {
+        match cell {
+            Cell::Value(Value::Bool(b)) => Some(*b),
+            _ => None,
+        }
+    }
+}
+
+impl FromCell for f64 {
+    fn from_cell(cell: &Cell) -> Option<Self> {
+        match cell {
+            Cell::Value(Value::Float(f)) => Some(*f),
+            _ => None,
+        }
+    }
+}
+
+impl FromCell for f32 {
+    fn from_cell(cell: &Cell) -> Option<Self> {
+        f64::from_cell(cell).map(|f| f as f32)
+    }
+}
+
+macro_rules! impl_from_cell_for_integer {
+    ($($t:ty),*) => {
+        $(
+            impl FromCell for $t {
+                fn from_cell(cell: &Cell) -> Option<Self> {
+                    use std::convert::TryFrom;
+                    match cell {
+                        Cell::Value(Value::Int(i)) => <$t>::try_from(*i).ok(),
+                        Cell::Value(Value::UInt(u)) => <$t>::try_from(*u).ok(),
+                        _ => None,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_from_cell_for_integer!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+impl FromCell for Timestamp {
+    fn from_cell(cell: &Cell) -> Option<Self> {
+        match cell {
+            Cell::Time(t) => Some(t.clone()),
+            _ => None,
+        }
+    }
+}
+
+impl<T: FromCell> FromCell for Option<T> {
+    fn from_cell(cell: &Cell) -> Option<Self> {
+        match cell {
+            Cell::Null => Some(None),
+            cell => T::from_cell(cell).map(Some),
+        }
+    }
+
+    fn from_missing() -> Option<Self> {
+        Some(None)
+    }
+}
+
+/// Data type of a column, taken from the `#datatype` annotation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DataType {
+    String,
+    Double,
+    Long,
+    UnsignedLong,
+    Boolean,
+    DateTime,
+    Duration,
+    Base64Binary,
+}
+
+impl DataType {
+    fn parse(s: &str) -> Option<DataType> {
+        match s {
+            "string" => Some(DataType::String),
+            "double" => Some(DataType::Double),
+            "long" => Some(DataType::Long),
+            "unsignedLong" => Some(DataType::UnsignedLong),
+            "boolean" => Some(DataType::Boolean),
+            "dateTime" | "dateTime:RFC3339" | "dateTime:RFC3339Nano" => Some(DataType::DateTime),
+            "duration" => Some(DataType::Duration),
+            "base64Binary" => Some(DataType::Base64Binary),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Column {
+    pub name: String,
+    pub data_type: DataType,
+    /// Whether the column is part of the group key.
+    pub group: bool,
+    /// Value used for empty cells.
+    pub default: Option<String>,
+}
+
+/// A table of a query result.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Table {
+    /// Name of the result set the table belongs to.
+    pub result: String,
+    pub id: i64,
+    pub columns: Vec<Column>,
+    pub records: Vec<Record>,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum CsvError {
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Malformed CSV at line {line}: {message}")]
+    Malformed { line: usize, message: String },
+    #[error("Flux error: {message} (reference: {reference:?})")]
+    Flux {
+        message: String,
+        reference: Option<String>,
+    },
+}
+
+/// Line-by-line parser state for annotated CSV.
+#[derive(Debug, Default)]
+struct Parser {
+    line: usize,
+    pending: Option<String>,
+    datatypes: Vec<String>,
+    groups: Vec<String>,
+    defaults: Vec<String>,
+    columns: Option<Vec<Column>>,
+    in_annotations: bool,
+    // Whether the current columns have been handed out by `take_new_columns`
+    columns_taken: bool,
+}
+
+impl Parser {
+    /// Feed one line to the parser. Returns a record if the line completed a data row.
+    fn push_line(&mut self, line: &str) -> Result<Option<Record>, CsvError> {
+        self.line += 1;
+        let line = line.strip_suffix('\r').unwrap_or(line);
+
+        // Quoted cells may span several lines
+        let row = match self.pending.take() {
+            Some(mut row) => {
+                row.push('\n');
+                row.push_str(line);
+                row
+            }
+            None => line.to_string(),
+        };
+        if row.matches('"').count() % 2 == 1 {
+            self.pending = Some(row);
+            return Ok(None);
+        }
+
+        self.push_row(row)
+    }
+
+    /// Signal the end of input.
+    fn finish(&mut self) -> Result<(), CsvError> {
+        match self.pending.take() {
+            Some(_) => Err(self.malformed("unterminated quoted cell")),
+            None => Ok(()),
+        }
+    }
+
+    fn push_row(&mut self, row: String) -> Result<Option<Record>, CsvError> {
+        // An empty line ends the current table
+        if row.is_empty() {
+            self.reset();
+            return Ok(None);
+        }
+
+        let cells = split_row(&row);
+
+        if cells[0].starts_with('#') {
+            // New annotations after data rows means a new table schema
+            if !self.in_annotations {
+                self.reset();
+                self.in_annotations = true;
+            }
+            match cells[0].as_str() {
+                "#datatype" => self.datatypes = cells,
+                "#group" => self.groups = cells,
+                "#default" => self.defaults = cells,
+                _ => {}
+            }
+            return Ok(None);
+        }
+        self.in_annotations = false;
+
+        if self.columns.is_none() {
+            self.columns = Some(self.header(cells)?);
+            return Ok(None);
+        }
+        self.record(cells).map(Some)
+    }
+
+    fn reset(&mut self) {
+        self.datatypes.clear();
+        self.groups.clear();
+        self.defaults.clear();
+        self.columns = None;
+        self.in_annotations = false;
+        self.columns_taken = false;
+    }
+
+    /// Get the columns of the current table schema, once per schema.
+    fn take_new_columns(&mut self) -> Option<Vec<Column>> {
+        if self.columns_taken {
+            return None;
+        }
+        self.columns_taken = true;
+        self.columns.clone()
+    }
+
+    fn header(&self, cells: Vec<String>) -> Result<Vec<Column>, CsvError> {
+        // The first column is reserved for annotations
+        cells
+            .into_iter()
+            .enumerate()
+            .skip(1)
+            .map(|(i, name)| {
+                let data_type = match self.datatypes.get(i) {
+                    Some(s) => DataType::parse(s).ok_or_else(|| {
+                        self.malformed(&format!("unknown datatype '{}' of column '{}'", s, name))
+                    })?,
+                    None => DataType::String,
+                };
+                Ok(Column {
+                    name,
+                    data_type,
+                    group: self.groups.get(i).is_some_and(|g| g == "true"),
+                    default: self.defaults.get(i).filter(|d| !d.is_empty()).cloned(),
+                })
+            })
+            .collect()
+    }
+
+    fn record(&self, cells: Vec<String>) -> Result<Record, CsvError> {
+        let columns = self.columns.as_deref().unwrap_or_default();
+        if cells.len() != columns.len() + 1 {
+            return Err(self.malformed(&format!(
+                "expected {} cells, got {}",
+                columns.len() + 1,
+                cells.len()
+            )));
+        }
+
+        let names = columns
+            .iter()
+            .map(|c| c.name.as_str())
+            .collect::<Vec<&str>>();
+        if names == ["error", "reference"] {
+            let reference = Some(cells[2].clone()).filter(|r| !r.is_empty());
+            return Err(CsvError::Flux {
+                message: cells[1].clone(),
+                reference,
+            });
+        }
+
+        let mut values = Vec::with_capacity(columns.len());
+        for (column, cell) in columns.iter().zip(cells.into_iter().skip(1)) {
+            let cell = match (cell.is_empty(), &column.default) {
+                (true, Some(default)) => default.clone(),
+                _ => cell,
+            };
+            values.push((column.name.clone(), self.cell(column, cell)?));
+        }
+        Ok(Record { values })
+    }
+
+    fn cell(&self, column: &Column, s: String) -> Result<Cell, CsvError> {
+        if s.is_empty() && column.data_type != DataType::String {
+            return Ok(Cell::Null);
+        }
+        let invalid = || {
+            self.malformed(&format!(
+                "invalid {:?} in column '{}': {}",
+                column.data_type, column.name, s
+            ))
+        };
+        let cell = match column.data_type {
+            DataType::String | DataType::Duration | DataType::Base64Binary => {
+                Cell::Value(Value::Str(s))
+            }
+            DataType::Double => Cell::Value(Value::Float(s.parse().map_err(|_| invalid())?)),
+            DataType::Long => Cell::Value(Value::Int(s.parse().map_err(|_| invalid())?)),
+            DataType::Boolean => Cell::Value(Value::Bool(s.parse().map_err(|_| invalid())?)),
+            DataType::UnsignedLong => Cell::Value(Value::UInt(s.parse().map_err(|_| invalid())?)),
+            DataType::DateTime => Cell::Time(Timestamp::Precise(
+                parse_rfc3339(&s).ok_or_else(invalid)?,
+                Precision::NS,
+            )),
+        };
+        Ok(cell)
+    }
+
+    fn malformed(&self, message: &str) -> CsvError {
+        CsvError::Malformed {
+            line: self.line,
+            message: message.to_string(),
+        }
+    }
+}
+
+/// Split a CSV row into cells, removing quotes.
+fn split_row(row: &str) -> Vec<String> {
+    let mut cells = Vec::new();
+    let mut cell = String::new();
+    let mut chars = row.chars().peekable();
+    let mut quoted = false;
+
+    while let Some(ch) = chars.next() {
+        match ch {
+            '"' if quoted => {
+                if chars.peek() == Some(&'"') {
+                    cell.push('"');
+                    chars.next();
+                } else {
+                    quoted = false;
+                }
+            }
+            '"' if cell.is_empty() => quoted = true,
+            ',' if !quoted => cells.push(std::mem::take(&mut cell)),
+            _ => cell.push(ch),
+        }
+    }
+    cells.push(cell);
+    cells
+}
+
+/// Parse an RFC3339 date-time into nanoseconds since the unix epoch.
+pub(crate) fn parse_rfc3339(s: &str) -> Option<i64> {
+    // Work on bytes, cells are untrusted and may hold multi-byte characters anywhere
+    let b = s.as_bytes();
+    let num = |range: std::ops::Range<usize>| -> Option<i64> {
+        let part = b.get(range)?;
+        if part.is_empty() || !part.iter().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        Some(part.iter().fold(0, |n, d| n * 10 + i64::from(d - b'0')))
+    };
+
+    if b.len() < 20
+        || b[4] != b'-'
+        || b[7] != b'-'
+        || !matches!(b[10], b'T' | b't' | b' ')
+        || b[13] != b':'
+        || b[16] != b':'
+    {
+        return None;
+    }
+    let (year, month, day) = (num(0..4)?, num(5..7)?, num(8..10)?);
+    let (hour, minute, second) = (num(11..13)?, num(14..16)?, num(17..19)?);
+    // A leap second of 60 is folded into the next minute
+    if !(1..=12).contains(&month)
+        || !(1..=31).contains(&day)
+        || hour > 23
+        || minute > 59
+        || second > 60
+    {
+        return None;
+    }
+
+    // Fractional seconds
+    let mut rest = &b[19..];
+    let mut nanos = 0;
+    if let Some((b'.', fraction)) = rest.split_first() {
+        let digits = fraction.iter().take_while(|b| b.is_ascii_digit()).count();
+        if digits == 0 || digits > 9 {
+            return None;
+        }
+        nanos = num(20..20 + digits)? * 10_i64.pow(9 - digits as u32);
+        rest = &fraction[digits..];
+    }
+
+    // Offset
+    let offset = match rest {
+        b"Z" | b"z" => 0,
+        [sign, h1, h2, b':', m1, m2] => {
+            let sign = match sign {
+                b'+' => 1,
+                b'-' => -1,
+                _ => return None,
+            };
+            let digit = |d: &u8| d.is_ascii_digit().then(|| i64::from(d - b'0'));
+            let hours = digit(h1)? * 10 + digit(h2)?;
+            let minutes = digit(m1)? * 10 + digit(m2)?;
+            sign * (hours * 3600 + minutes * 60)
+        }
+        _ => return None,
+    };
+
+    // Days since epoch, refer: <http://howardhinnant.github.io/date_algorithms.html#days_from_civil>
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = if y >= 0 { y } else { y - 399 } / 400;
+    let yoe = y - era * 400;
+    let doy = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    let days = era * 146097 + doe - 719468;
+
+    let seconds = days * 86400 + hour * 3600 + minute * 60 + second - offset;
+    seconds.checked_mul(1_000_000_000)?.checked_add(nanos)
+}
+
+/// Iterator over the records of annotated CSV from a [`Read`] source.
+pub struct Reader<R> {
+    lines: std::io::Lines<BufReader<R>>,
+    parser: Parser,
+    done: bool,
+}
+
+impl<R: Read> Reader<R> {
+    pub fn new(reader: R) -> Self {
+        Reader {
+            lines: BufReader::new(reader).lines(),
+            parser: Parser::default(),
+            done: false,
+        }
+    }
+
+    /// Read the remaining records grouped into tables.
+    pub fn tables(mut self) -> Result<Vec<Table>, CsvError> {
+        let mut tables = TableCollector::default();
+        while let Some(record) = self.next() {
+            let record = record?;
+            tables.push(self.parser.take_new_columns(), record);
+        }
+        Ok(tables.tables)
+    }
+}
+
+impl<R: Read> Iterator for Reader<R> {
+    type Item = Result<Record, CsvError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.done {
+            match self.lines.next() {
+                Some(Ok(line)) => match self.parser.push_line(&line) {
+                    Ok(Some(record)) => return Some(Ok(record)),
+                    Ok(None) => {}
+                    Err(e) => return Some(Err(e)),
+                },
+                Some(Err(e)) => return Some(Err(e.into())),
+                None => {
+                    self.done = true;
+                    if let Err(e) = self.parser.finish() {
+                        return Some(Err(e));
+                    }
+                }
+            }
+        }
+        None
+    }
+}
+
+/// Read all tables of annotated CSV from a [`Read`] source.
+pub fn read_tables<R: Read>(reader: R) -> Result<Vec<Table>, CsvError> {
+    Reader::new(reader).tables()
+}
+
+/// Stream the records of annotated CSV from an [`AsyncRead`] source.
+pub fn read_records_async<R: AsyncRead + Unpin>(
+    reader: R,
+) -> impl Stream<Item = Result<Record, CsvError>> {
+    records_with_columns(reader).map_ok(|(_, record)| record)
+}
+
+/// Read all tables of annotated CSV from an [`AsyncRead`] source.
+pub async fn read_tables_async<R: AsyncRead + Unpin>(reader: R) -> Result<Vec<Table>, CsvError> {
+    let mut tables = TableCollector::default();
+    let mut records = Box::pin(records_with_columns(reader));
+    while let Some((columns, record)) = records.try_next().await? {
+        tables.push(columns, record);
+    }
+    Ok(tables.tables)
+}
+
+/// Stream records, along with the columns of their table for the first record of a schema.
+fn records_with_columns<R: AsyncRead + Unpin>(
+    reader: R,
+) -> impl Stream<Item = Result<(Option<Vec<Column>>, Record), CsvError>> {
+    let lines = AsyncBufReader::new(reader).lines();
+    futures::stream::unfold(
+        (lines, Parser::default(), false),
+        |(mut lines, mut parser, done)| async move {
+            if done {
+                return None;
+            }
+            loop {
+                let item = match lines.next().await {
+                    Some(Ok(line)) => match parser.push_line(&line) {
+                        Ok(Some(record)) => Ok((parser.take_new_columns(), record)),
+                        Ok(None) => continue,
+                        Err(e) => Err(e),
+                    },
+                    Some(Err(e)) => Err(e.into()),
+                    None => match parser.finish() {
+                        Ok(()) => return None,
+                        Err(e) => return Some((Err(e), (lines, parser, true))),
+                    },
+                };
+                return Some((item, (lines, parser, false)));
+            }
+        },
+    )
+}
+
+/// Groups records into tables by schema, result and table id.
+#[derive(Default)]
+struct TableCollector {
+    tables: Vec<Table>,
+}
+
+impl TableCollector {
+    /// `columns` is given for the first record of a new table schema.
+    fn push(&mut self, columns: Option<Vec<Column>>, record: Record) {
+        let result = record.result().unwrap_or_default().to_string();
+        let id = record.table().unwrap_or_default();
+
+        if let (None, Some(table)) = (&columns, self.tables.last_mut()) {
+            if table.result == result && table.id == id {
+                table.records.push(record);
+                return;
+            }
+        }
+
+        let columns = columns
+            .or_else(|| self.tables.last().map(|t| t.columns.clone()))
+            .unwrap_or_default();
+        self.tables.push(Table {
+            result,
+            id,
+            columns,
+            records: vec![record],
+        });
+    }
+}
@@ -0,0 +1,129 @@
+//! Delete data by time range and predicate, refer: <https://docs.influxdata.com/influxdb/v2.0/reference/syntax/delete-predicate/>
+use std::fmt;
+
+use reqwest::Method;
+
+use crate::{client::Client, models::InfluxError, traits::ToTimestamp};
+
+/// Which points to delete, by measurement and tag values.
+///
+/// Delete predicates only support equality joined by `AND`. Tag keys that are no plain
+/// identifiers are quoted like values.
+///
+/// # Example
+/// ```
+/// use influxdb_client::Predicate;
+///
+/// let predicate = Predicate::measurement("orders").and_tag("customer", "42");
+/// assert_eq!(predicate.to_string(), r#"_measurement="orders" AND customer="42""#);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Predicate {
+    conditions: Vec<(String, String)>,
+}
+
+impl Predicate {
+    /// Points of a measurement.
+    pub fn measurement(measurement: impl Into<String>) -> Self {
+        Predicate::default().and_measurement(measurement)
+    }
+
+    /// Points with a tag value.
+    pub fn tag(key: impl Into<String>, value: impl Into<String>) -> Self {
+        Predicate::default().and_tag(key, value)
+    }
+
+    pub fn and_measurement(self, measurement: impl Into<String>) -> Self {
+        self.and_tag("_measurement", measurement)
+    }
+
+    pub fn and_tag(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.conditions.push((key.into(), value.into()));
+        self
+    }
+}
+
+fn write_quoted(f: &mut fmt::Formatter<'_>, s: &str) -> fmt::Result {
+    write!(f, "\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Whether `s` can be written without quotes, i.e. is a name and no keyword.
+fn is_identifier(s: &str) -> bool {
+    let mut chars = s.chars();
+    matches!(chars.next(), Some(c) if c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+        && !s.eq_ignore_ascii_case("and")
+        && !s.eq_ignore_ascii_case("or")
+}
+
+impl fmt::Display for Predicate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, (key, value)) in self.conditions.iter().enumerate() {
+            if i > 0 {
+                f.write_str(" AND ")?;
+            }
+            if is_identifier(key) {
+                f.write_str(key)?;
+            } else {
+                write_quoted(f, key)?;
+            }
+            f.write_str("=")?;
+            write_quoted(f, value)?;
+        }
+        Ok(())
+    }
+}
+
+impl Client {
+    /// Delete points between `start` and `stop` in the bucket of the client, optionally
+    /// only those matching `predicate`.
+    ///
+    /// Times are converted to the precision of the client, and integer timestamps without
+    /// a unit are read in it. An empty `Option` fails with [`InfluxError::MissingTimestamp`].
+    ///
+    /// # Example
+    /// ```no_run
+    /// # async fn example() -> Result<(), influxdb_client::InfluxError> {
+    /// use std::time::SystemTime;
+    /// use influxdb_client::{Client, Predicate, Timestamp};
+    ///
+    /// let client = Client::new("http://localhost:8086", "token")
+    ///     .unwrap()
+    ///     .with_org("tradely")
+    ///     .with_bucket("orders");
+    /// client
+    ///     .delete(
+    ///         Timestamp::parse_rfc3339("1970-01-01T00:00:00Z").unwrap(),
+    ///         SystemTime::now(),
+    ///         Some(&Predicate::tag("customer", "42")),
+    ///     )
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn delete(
+        &self,
+        start: impl ToTimestamp,
+        stop: impl ToTimestamp,
+        predicate: Option<&Predicate>,
+    ) -> Result<(), InfluxError> {
+        let rfc3339 = |time: &dyn ToTimestamp| {
+            let timestamp = time
+                .to_timestamp(self.precision)?
+                .ok_or(InfluxError::MissingTimestamp)?;
+            timestamp
+                .to_rfc3339(self.precision)
+                .ok_or(InfluxError::InvalidTimestamp(timestamp))
+        };
+        let mut body = serde_json::json!({
+            "start": rfc3339(&start)?,
+            "stop": rfc3339(&stop)?,
+        });
+        if let Some(predicate) = predicate {
+            body["predicate"] = predicate.to_string().into();
+        }
+
+        let request = self.new_request(Method::POST, "/api/v2/delete").json(&body);
+        self.send_empty(request).await
+    }
+}
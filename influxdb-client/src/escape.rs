@@ -30,22 +30,22 @@ macro_rules! escape {
 /// Make an escaped string for tag and field keys
 ///
 /// For tag keys, tag values, and field keys always use a backslash character \ to escape
-pub fn escape_tag_and_field_keys(s: &str) -> Cow<str> {
+pub fn escape_tag_and_field_keys(s: &str) -> Cow<'_, str> {
     escape!(s, ',' | '=' | ' ')
 }
 
 /// Make an escaped string for tag and field keys
 ///
 /// For measurements always use a backslash character \ to escape
-pub fn escape_measurement(s: &str) -> Cow<str> {
+pub fn escape_measurement(s: &str) -> Cow<'_, str> {
     escape!(s, ',' | ' ')
 }
 
 /// Make an escaped string for field value
 ///
 /// The returned string is *not* quoted in double quotes.
-pub fn escape_field_value_string(s: &str) -> Cow<str> {
-    escape!(s, '"')
+pub fn escape_field_value_string(s: &str) -> Cow<'_, str> {
+    escape!(s, '"' | '\\')
 }
 
 #[cfg(test)]
@@ -71,5 +71,6 @@ mod tests {
     #[test]
     fn test_string_escape() {
         assert_eq!(escape_field_value_string("too\"hot"), "too\\\"hot");
+        assert_eq!(escape_field_value_string(r"C:\temp\"), r"C:\\temp\\");
     }
 }
@@ -0,0 +1,146 @@
+//! Health, readiness and ping of the server.
+use std::time::{Duration, Instant};
+
+use reqwest::{Method, StatusCode};
+use serde::Deserialize;
+
+use crate::{
+    client::{check_status, Client},
+    models::{ApiError, InfluxError},
+};
+
+/// Health of the server and its dependencies, from `/health`.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Health {
+    pub name: String,
+    #[serde(default)]
+    pub message: Option<String>,
+    pub status: HealthStatus,
+    #[serde(default)]
+    pub checks: Vec<Health>,
+    #[serde(default)]
+    pub version: Option<String>,
+    #[serde(default)]
+    pub commit: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HealthStatus {
+    Pass,
+    Fail,
+}
+
+/// Readiness of the server, from `/ready`.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Ready {
+    pub status: String,
+    /// Start time of the server, as RFC3339 date-time.
+    #[serde(default)]
+    pub started: Option<String>,
+    /// Uptime of the server, e.g. `1h2m3.45s`.
+    #[serde(default)]
+    pub up: Option<String>,
+}
+
+/// Version and build of the server, from `/ping`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Ping {
+    pub version: Option<String>,
+    pub build: Option<String>,
+}
+
+impl Client {
+    /// Health of the server. An unhealthy server is reported by [`HealthStatus::Fail`].
+    pub async fn health(&self) -> Result<Health, InfluxError> {
+        let response = self.api_request(Method::GET, "/health").send().await?;
+        if response.status() == StatusCode::SERVICE_UNAVAILABLE {
+            // Unhealthy servers still describe themselves
+            let content = response.text().await?;
+            return serde_json::from_str(&content)
+                .map_err(|_| InfluxError::from_response(ApiError::new(503, content)));
+        }
+        Ok(check_status(response).await?.json().await?)
+    }
+
+    /// Readiness of the server. A server that is not ready fails with an error.
+    pub async fn ready(&self) -> Result<Ready, InfluxError> {
+        let request = self.api_request(Method::GET, "/ready");
+        self.send_json(request).await
+    }
+
+    /// Like [`Client::ready`], giving up after `timeout` instead of the timeout of the client.
+    async fn ready_within(&self, timeout: Duration) -> Result<Ready, InfluxError> {
+        let request = self.api_request(Method::GET, "/ready").timeout(timeout);
+        self.send_json(request).await
+    }
+
+    /// Check that the server is reachable, returning its version and build.
+    pub async fn ping(&self) -> Result<Ping, InfluxError> {
+        let response = self.api_request(Method::GET, "/ping").send().await?;
+        let response = check_status(response).await?;
+        let header = |name: &str| {
+            response
+                .headers()
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .map(str::to_string)
+        };
+        Ok(Ping {
+            version: header("X-Influxdb-Version"),
+            build: header("X-Influxdb-Build"),
+        })
+    }
+
+    /// Wait until the server is ready, retrying with backoff for at most `timeout`.
+    ///
+    /// Requests time out after at most 3 seconds and never after the deadline. When the next
+    /// backoff delay would pass the deadline, a last attempt is made right away with the time
+    /// that is left. Fails with [`InfluxError::RetriesExhausted`] if the server is not ready in time.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # async fn example() -> Result<(), influxdb_client::InfluxError> {
+    /// use std::time::Duration;
+    /// use influxdb_client::Client;
+    ///
+    /// let client = Client::new("http://localhost:8086", "token").unwrap();
+    /// client.wait_until_ready(Duration::from_secs(30)).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn wait_until_ready(&self, timeout: Duration) -> Result<Ready, InfluxError> {
+        let deadline = Instant::now() + timeout;
+        let mut backoff = Duration::from_millis(100);
+        let mut attempts = 0;
+        let mut last_attempt = false;
+        loop {
+            attempts += 1;
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            let error = match self
+                .ready_within(remaining.min(Duration::from_secs(3)))
+                .await
+            {
+                Ok(ready) => return Ok(ready),
+                Err(error) if !error.is_retryable() => return Err(error),
+                Err(error) => error,
+            };
+
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if last_attempt || remaining.is_zero() {
+                return Err(InfluxError::RetriesExhausted {
+                    attempts,
+                    source: Box::new(error),
+                });
+            }
+
+            let delay = error.retry_after().unwrap_or(backoff);
+            if delay < remaining {
+                tokio::time::sleep(delay).await;
+            } else {
+                last_attempt = true;
+            }
+            backoff = (backoff * 2).min(Duration::from_secs(5));
+        }
+    }
+}
@@ -1,15 +1,35 @@
+pub mod api;
+mod batch;
 mod client;
+pub mod csv;
+mod delete;
 mod escape;
+mod health;
 mod macros;
 mod models;
+mod parser;
+mod retry;
+mod setup;
+mod timestamp;
 mod traits;
+mod v1;
 
 // From library
+pub use crate::batch::{BatchOptions, BatchWriter};
 pub use crate::client::Client;
-pub use crate::models::{InfluxError, Point, Precision, Timestamp, TimestampOptions, Value};
-pub use crate::traits::PointSerialize;
+pub use crate::csv::{Cell, CsvError, Record, RecordError, Table};
+pub use crate::delete::Predicate;
+pub use crate::health::{Health, HealthStatus, Ping, Ready};
+pub use crate::models::{
+    ApiError, InfluxError, Point, Precision, Timestamp, TimestampOptions, Value,
+};
+pub use crate::parser::{parse_lines, ParseError};
+pub use crate::retry::RetryPolicy;
+pub use crate::setup::Setup;
+pub use crate::traits::{FromCell, FromRecord, PointSerialize, ToTimestamp};
+pub use crate::v1::Series;
 
 // Derives
 pub mod derives {
-    pub use influxdb_derives::PointSerialize;
+    pub use influxdb_derives::{FromRecord, PointSerialize};
 }
@@ -2,10 +2,11 @@ use crate::escape;
 use crate::traits::PointSerialize;
 use std::fmt::Write;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum Value {
     Str(String),
     Int(i64),
+    UInt(u64),
     Float(f64),
     Bool(bool),
 }
@@ -16,28 +17,56 @@ impl From<&str> for Value {
     }
 }
 
+impl From<String> for Value {
+    fn from(v: String) -> Value {
+        Value::Str(v)
+    }
+}
+
 impl From<f64> for Value {
     fn from(v: f64) -> Value {
         Value::Float(v)
     }
 }
 
-impl From<i64> for Value {
-    fn from(v: i64) -> Value {
-        Value::Int(v)
+impl From<f32> for Value {
+    fn from(v: f32) -> Value {
+        // Go through the shortest decimal representation, so 0.1f32 becomes 0.1 and not 0.10000000149011612
+        Value::Float(v.to_string().parse().unwrap())
     }
 }
 
+macro_rules! impl_from_for_value {
+    ($variant:ident, $target:ty, $($t:ty),*) => {
+        $(
+            impl From<$t> for Value {
+                fn from(v: $t) -> Value {
+                    Value::$variant(v as $target)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_for_value!(Int, i64, i8, i16, i32, i64, isize);
+impl_from_for_value!(UInt, u64, u8, u16, u32, u64, usize);
+
 impl From<bool> for Value {
     fn from(v: bool) -> Value {
         Value::Bool(v)
     }
 }
 
-#[derive(Debug, Clone)]
+/// Timestamp of a point.
+///
+/// `Str` and `Int` are written as they are, in the precision of the client, and are not rescaled.
+/// `Precise` carries its own unit and is rescaled to the precision of the client when written,
+/// so prefer it for values that are not already in the precision of the client.
+#[derive(Debug, Clone, PartialEq)]
 pub enum Timestamp {
     Str(String),
     Int(i64),
+    Precise(i64, Precision),
 }
 
 impl From<&str> for Timestamp {
@@ -46,22 +75,25 @@ impl From<&str> for Timestamp {
     }
 }
 
+/// The value is written unscaled, in the precision of the client. Use [`Timestamp::Precise`]
+/// for a value with its own unit.
 impl From<i64> for Timestamp {
     fn from(v: i64) -> Timestamp {
         Timestamp::Int(v)
     }
 }
 
-impl std::string::ToString for Timestamp {
-    fn to_string(&self) -> String {
+impl std::fmt::Display for Timestamp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            Timestamp::Str(s) => s.to_string(),
-            Timestamp::Int(i) => i.to_string(),
+            Timestamp::Str(s) => write!(f, "{}", s),
+            Timestamp::Int(i) => write!(f, "{}", i),
+            Timestamp::Precise(i, _) => write!(f, "{}", i),
         }
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Point {
     pub measurement: String,
     pub timestamp: Option<Timestamp>,
@@ -129,15 +161,14 @@ impl PointSerialize for Point {
                 builder.push('=');
                 match field_value {
                     Value::Str(s) => {
-                        write!(
-                            &mut builder,
-                            "\"{}\"",
-                            escape::escape_field_value_string(&s)
-                        )
-                        .unwrap();
+                        write!(&mut builder, "\"{}\"", escape::escape_field_value_string(s))
+                            .unwrap();
                     }
                     Value::Int(i) => {
-                        write!(&mut builder, "{}", i).unwrap();
+                        write!(&mut builder, "{}i", i).unwrap();
+                    }
+                    Value::UInt(u) => {
+                        write!(&mut builder, "{}u", u).unwrap();
                     }
                     Value::Float(f) => {
                         write!(&mut builder, "{}", f).unwrap();
@@ -157,17 +188,94 @@ impl PointSerialize for Point {
 
     fn serialize_with_timestamp(&self, timestamp: Option<Timestamp>) -> String {
         match timestamp {
-            Some(timestamp) => format!("{} {}", self.serialize(), timestamp.to_string()),
+            Some(timestamp) => format!("{} {}", self.serialize(), timestamp),
             None => format!(
                 "{} {}",
                 self.serialize(),
-                self.timestamp
-                    .clone()
-                    .unwrap_or_else(|| Timestamp::from(0))
-                    .to_string()
+                self.timestamp.clone().unwrap_or_else(|| Timestamp::from(0))
             ),
         }
     }
+
+    fn serialize_with_precision(
+        &self,
+        timestamp: Option<Timestamp>,
+        precision: Precision,
+        strict: bool,
+    ) -> Result<String, InfluxError> {
+        let timestamp = timestamp
+            .or_else(|| self.timestamp.clone())
+            .unwrap_or_else(|| Timestamp::from(0));
+        Ok(format!(
+            "{} {}",
+            self.serialize(),
+            timestamp.rescale(precision, strict)?
+        ))
+    }
+
+    fn validate(&self) -> Result<(), InfluxError> {
+        if self.fields.is_empty() {
+            return Err(InfluxError::NoFields(self.measurement.clone()));
+        }
+        Ok(())
+    }
+}
+
+/// Error response from InfluxDB.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ApiError {
+    /// HTTP status code of the response.
+    pub status: u16,
+    /// Error code from the response body, e.g. `invalid` or `not found`.
+    pub code: Option<String>,
+    /// Error message from the response body, or the whole body if it is not JSON.
+    pub message: String,
+    /// Line of the written line protocol that caused the error.
+    pub line: Option<u32>,
+    /// How long the server asked to wait before retrying, from the `Retry-After` header.
+    pub retry_after: Option<std::time::Duration>,
+}
+
+impl ApiError {
+    /// Build from a status code and a response body.
+    pub(crate) fn new(status: u16, body: String) -> Self {
+        #[derive(serde::Deserialize)]
+        struct Body {
+            code: Option<String>,
+            message: Option<String>,
+            line: Option<u32>,
+        }
+
+        match serde_json::from_str::<Body>(&body) {
+            Ok(Body {
+                code,
+                message: Some(message),
+                line,
+            }) => ApiError {
+                status,
+                code,
+                message,
+                line,
+                retry_after: None,
+            },
+            _ => ApiError {
+                status,
+                code: None,
+                message: body,
+                line: None,
+                retry_after: None,
+            },
+        }
+    }
+}
+
+impl std::fmt::Display for ApiError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.code {
+            Some(code) => write!(f, "{} ({})", self.message, code),
+            None => write!(f, "{}", self.message),
+        }
+    }
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -175,13 +283,130 @@ pub enum InfluxError {
     #[error("Network error: {0:?}")]
     Network(#[from] reqwest::Error),
     #[error("Invalid syntax: {0}")]
-    InvalidSyntax(String),
+    InvalidSyntax(ApiError),
     #[error("Invalid credentials: {0}")]
-    InvalidCredentials(String),
+    InvalidCredentials(ApiError),
     #[error("Forbidden: {0}")]
-    Forbidden(String),
+    Forbidden(ApiError),
+    #[error("Not found: {0}")]
+    NotFound(ApiError),
+    #[error("Conflict: {0}")]
+    Conflict(ApiError),
+    #[error("Payload too large: {0}")]
+    PayloadTooLarge(ApiError),
+    #[error("Unprocessable entity: {0}")]
+    UnprocessableEntity(ApiError),
+    #[error("Rate limited: {0}")]
+    RateLimited(ApiError),
+    #[error("Internal server error: {0}")]
+    ServerError(ApiError),
+    #[error("Bad gateway: {0}")]
+    BadGateway(ApiError),
+    #[error("Service unavailable: {0}")]
+    ServiceUnavailable(ApiError),
+    #[error("Gateway timeout: {0}")]
+    GatewayTimeout(ApiError),
+    #[error("Giving up after {attempts} attempts: {source}")]
+    RetriesExhausted {
+        attempts: u32,
+        source: Box<InfluxError>,
+    },
+    #[error(transparent)]
+    Csv(#[from] crate::csv::CsvError),
+    #[error(transparent)]
+    Record(#[from] crate::csv::RecordError),
+    #[error("Point of measurement '{0}' has no fields")]
+    NoFields(String),
+    #[error("Timestamp {timestamp} cannot be written with precision {precision:?}")]
+    TimestampPrecision {
+        timestamp: Timestamp,
+        precision: Precision,
+    },
+    #[error("Time is out of range for precision {0:?}")]
+    TimestampOutOfRange(Precision),
+    #[error("Timestamp {0} is not a valid time")]
+    InvalidTimestamp(Timestamp),
+    #[error("No time given")]
+    MissingTimestamp,
+    #[error("No organization configured or found, use Client::with_org or Client::with_org_id")]
+    MissingOrg,
+    #[error("Server is already set up")]
+    AlreadySetUp,
+    #[error("Server returned no token for the new user")]
+    MissingToken,
+    #[error("InfluxQL error: {0}")]
+    InfluxQl(String),
+    #[error("{count} batch writes failed, the first with: {first}")]
+    WritesFailed {
+        count: usize,
+        first: Box<InfluxError>,
+        last: Box<InfluxError>,
+    },
+    #[error("Batch buffer is full, dropped the {0} oldest lines")]
+    LinesDropped(usize),
+    #[error("Batch writer is closed")]
+    WriterClosed,
     #[error("Unknown error: {0}")]
-    Unknown(String),
+    Unknown(ApiError),
+}
+
+impl InfluxError {
+    /// Build the error for a non-success response.
+    pub(crate) fn from_response(error: ApiError) -> Self {
+        match error.status {
+            400 => InfluxError::InvalidSyntax(error),
+            401 => InfluxError::InvalidCredentials(error),
+            403 => InfluxError::Forbidden(error),
+            404 => InfluxError::NotFound(error),
+            409 => InfluxError::Conflict(error),
+            413 => InfluxError::PayloadTooLarge(error),
+            422 => InfluxError::UnprocessableEntity(error),
+            429 => InfluxError::RateLimited(error),
+            502 => InfluxError::BadGateway(error),
+            503 => InfluxError::ServiceUnavailable(error),
+            504 => InfluxError::GatewayTimeout(error),
+            500..=599 => InfluxError::ServerError(error),
+            _ => InfluxError::Unknown(error),
+        }
+    }
+
+    /// The error response from InfluxDB, if the error was caused by one.
+    pub fn api_error(&self) -> Option<&ApiError> {
+        match self {
+            InfluxError::InvalidSyntax(e)
+            | InfluxError::InvalidCredentials(e)
+            | InfluxError::Forbidden(e)
+            | InfluxError::NotFound(e)
+            | InfluxError::Conflict(e)
+            | InfluxError::PayloadTooLarge(e)
+            | InfluxError::UnprocessableEntity(e)
+            | InfluxError::RateLimited(e)
+            | InfluxError::ServerError(e)
+            | InfluxError::BadGateway(e)
+            | InfluxError::ServiceUnavailable(e)
+            | InfluxError::GatewayTimeout(e)
+            | InfluxError::Unknown(e) => Some(e),
+            InfluxError::RetriesExhausted { source, .. } => source.api_error(),
+            _ => None,
+        }
+    }
+
+    /// Whether the request may succeed if it is sent again.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            InfluxError::Network(e) => e.is_timeout() || e.is_connect(),
+            InfluxError::RateLimited(_)
+            | InfluxError::BadGateway(_)
+            | InfluxError::ServiceUnavailable(_)
+            | InfluxError::GatewayTimeout(_) => true,
+            _ => false,
+        }
+    }
+
+    /// How long the server asked to wait before retrying.
+    pub fn retry_after(&self) -> Option<std::time::Duration> {
+        self.api_error().and_then(|e| e.retry_after)
+    }
 }
 
 #[derive(Clone)]
@@ -191,7 +416,24 @@ pub enum TimestampOptions {
     FromPoint,
 }
 
-#[derive(Debug, Clone, Copy)]
+impl TimestampOptions {
+    pub(crate) fn serialize(
+        &self,
+        point: &impl PointSerialize,
+        precision: Precision,
+        strict: bool,
+    ) -> Result<String, InfluxError> {
+        match self {
+            TimestampOptions::Use(t) => {
+                point.serialize_with_precision(Some(t.clone()), precision, strict)
+            }
+            TimestampOptions::FromPoint => point.serialize_with_precision(None, precision, strict),
+            TimestampOptions::None => Ok(point.serialize()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Precision {
     NS,
     US,
@@ -0,0 +1,229 @@
+//! Parse line protocol into points.
+//!
+//! refer: <https://docs.influxdata.com/influxdb/v2.0/reference/syntax/line-protocol/>
+use std::str::FromStr;
+
+use crate::models::{Point, Timestamp, Value};
+
+/// Error from parsing line protocol, with the 1-based position of the problem.
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+#[error("Invalid line protocol at line {line}, column {column}: {message}")]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+/// Parse line protocol into points.
+///
+/// Empty lines and comments starting with `#` are skipped, leading whitespace is ignored.
+///
+/// # Example
+/// ```
+/// use influxdb_client::{parse_lines, Value};
+///
+/// let points = parse_lines("mem,host=host1 used_percent=23.4,active=t 1556813561098000000").unwrap();
+/// assert_eq!(points[0].fields[1], ("active".to_string(), Value::Bool(true)));
+/// ```
+pub fn parse_lines(text: &str) -> Result<Vec<Point>, ParseError> {
+    text.lines()
+        .enumerate()
+        .filter(|(_, line)| {
+            let line = line.trim_start();
+            !line.is_empty() && !line.starts_with('#')
+        })
+        .map(|(i, line)| parse_line(line.strip_suffix('\r').unwrap_or(line), i + 1))
+        .collect()
+}
+
+impl FromStr for Point {
+    type Err = ParseError;
+
+    /// Parse a single line of line protocol.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_line(s, 1)
+    }
+}
+
+fn parse_line(line: &str, number: usize) -> Result<Point, ParseError> {
+    // Skip leading whitespace, but keep it for the columns of errors
+    let chars: Vec<char> = line.chars().collect();
+    let mut cursor = Cursor {
+        pos: chars.iter().take_while(|c| c.is_whitespace()).count(),
+        chars,
+        line: number,
+    };
+
+    // Measurement
+    let measurement = cursor.escaped(&[',', ' '], &[',', ' ']);
+    if measurement.is_empty() {
+        return Err(cursor.error("missing measurement"));
+    }
+    let mut point = Point::new(measurement);
+
+    // Tags
+    while cursor.eat(',') {
+        let key = cursor.key("tag")?;
+        let value = cursor.escaped(&[',', ' ', '='], &[',', '=', ' ']);
+        if value.is_empty() {
+            return Err(cursor.error(&format!("missing value of tag '{}'", key)));
+        }
+        point.tags.push((key, value));
+    }
+
+    // Fields
+    if !cursor.eat(' ') {
+        return Err(cursor.error("missing fields"));
+    }
+    loop {
+        let key = cursor.key("field")?;
+        let value = cursor.field_value(&key)?;
+        point.fields.push((key, value));
+        if !cursor.eat(',') {
+            break;
+        }
+    }
+
+    // Timestamp
+    if cursor.eat(' ') {
+        let start = cursor.pos;
+        let timestamp = cursor.until(&[' ']);
+        let timestamp = timestamp
+            .parse::<i64>()
+            .map_err(|_| cursor.error_at(start, &format!("invalid timestamp '{}'", timestamp)))?;
+        point.timestamp = Some(Timestamp::Int(timestamp));
+    }
+
+    if cursor.pos < cursor.chars.len() {
+        return Err(cursor.error("unexpected trailing characters"));
+    }
+    Ok(point)
+}
+
+struct Cursor {
+    chars: Vec<char>,
+    pos: usize,
+    line: usize,
+}
+
+impl Cursor {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn eat(&mut self, ch: char) -> bool {
+        if self.peek() == Some(ch) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Read until one of `stops`, without handling escapes.
+    fn until(&mut self, stops: &[char]) -> String {
+        let start = self.pos;
+        while self.peek().is_some_and(|c| !stops.contains(&c)) {
+            self.pos += 1;
+        }
+        self.chars[start..self.pos].iter().collect()
+    }
+
+    /// Read until one of `stops` that is not escaped by a backslash.
+    ///
+    /// A backslash is removed only when it escapes one of `escapable`.
+    fn escaped(&mut self, stops: &[char], escapable: &[char]) -> String {
+        let mut s = String::new();
+        while let Some(ch) = self.peek() {
+            if stops.contains(&ch) {
+                break;
+            }
+            self.pos += 1;
+            match (ch, self.peek()) {
+                ('\\', Some(next)) if escapable.contains(&next) => {
+                    s.push(next);
+                    self.pos += 1;
+                }
+                _ => s.push(ch),
+            }
+        }
+        s
+    }
+
+    /// Read a tag or field key and the following `=`.
+    fn key(&mut self, kind: &str) -> Result<String, ParseError> {
+        let key = self.escaped(&[',', ' ', '='], &[',', '=', ' ']);
+        if key.is_empty() {
+            return Err(self.error(&format!("missing {} key", kind)));
+        }
+        if !self.eat('=') {
+            return Err(self.error(&format!("expected '=' after {} key '{}'", kind, key)));
+        }
+        Ok(key)
+    }
+
+    fn field_value(&mut self, key: &str) -> Result<Value, ParseError> {
+        let start = self.pos;
+
+        if self.eat('"') {
+            let mut s = String::new();
+            loop {
+                match self.peek() {
+                    None => return Err(self.error_at(start, "unterminated string")),
+                    Some('"') => {
+                        self.pos += 1;
+                        return Ok(Value::Str(s));
+                    }
+                    Some('\\') if matches!(self.chars.get(self.pos + 1), Some('"' | '\\')) => {
+                        s.push(self.chars[self.pos + 1]);
+                        self.pos += 2;
+                    }
+                    Some(ch) => {
+                        s.push(ch);
+                        self.pos += 1;
+                    }
+                }
+            }
+        }
+
+        let raw = self.until(&[',', ' ']);
+        let invalid = || {
+            self.error_at(
+                start,
+                &format!("invalid value of field '{}': '{}'", key, raw),
+            )
+        };
+        let value = match raw.as_str() {
+            "" => return Err(self.error_at(start, &format!("missing value of field '{}'", key))),
+            "t" | "T" | "true" | "True" | "TRUE" => Value::Bool(true),
+            "f" | "F" | "false" | "False" | "FALSE" => Value::Bool(false),
+            _ if raw.ends_with('i') => {
+                Value::Int(raw[..raw.len() - 1].parse().map_err(|_| invalid())?)
+            }
+            _ if raw.ends_with('u') => {
+                Value::UInt(raw[..raw.len() - 1].parse().map_err(|_| invalid())?)
+            }
+            _ => {
+                let f: f64 = raw.parse().map_err(|_| invalid())?;
+                // Rust accepts "inf" and "NaN", line protocol does not
+                if !f.is_finite() {
+                    return Err(invalid());
+                }
+                Value::Float(f)
+            }
+        };
+        Ok(value)
+    }
+
+    fn error(&self, message: &str) -> ParseError {
+        self.error_at(self.pos, message)
+    }
+
+    fn error_at(&self, pos: usize, message: &str) -> ParseError {
+        ParseError {
+            line: self.line,
+            column: pos + 1,
+            message: message.to_string(),
+        }
+    }
+}
@@ -0,0 +1,133 @@
+//! Retry failed writes with exponential backoff.
+use std::future::Future;
+use std::time::{Duration, Instant};
+
+use rand::Rng;
+
+use crate::models::InfluxError;
+
+/// How failed writes are retried.
+///
+/// Only retryable failures are retried, see [`InfluxError::is_retryable`].
+/// If the server sends a `Retry-After` header, it is used instead of the backoff.
+///
+/// # Example
+/// ```
+/// use std::time::Duration;
+/// use influxdb_client::{Client, RetryPolicy};
+///
+/// let client = Client::new("http://localhost:8086", "token")
+///     .unwrap()
+///     .with_retry_policy(
+///         RetryPolicy::default()
+///             .with_max_attempts(3)
+///             .with_initial_interval(Duration::from_millis(500)),
+///     );
+/// ```
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    initial_interval: Duration,
+    max_interval: Duration,
+    multiplier: f64,
+    jitter: f64,
+    max_elapsed_time: Option<Duration>,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_attempts: 5,
+            initial_interval: Duration::from_secs(1),
+            max_interval: Duration::from_secs(30),
+            multiplier: 2.0,
+            jitter: 0.2,
+            max_elapsed_time: Some(Duration::from_secs(180)),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Total number of attempts, including the first one. Defaults to 5.
+    pub fn with_max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// Delay before the first retry. Defaults to 1 second.
+    pub fn with_initial_interval(mut self, initial_interval: Duration) -> Self {
+        self.initial_interval = initial_interval;
+        self
+    }
+
+    /// Upper bound of the backoff delay. Defaults to 30 seconds.
+    pub fn with_max_interval(mut self, max_interval: Duration) -> Self {
+        self.max_interval = max_interval;
+        self
+    }
+
+    /// Factor the delay grows by after each attempt. Defaults to 2.
+    pub fn with_multiplier(mut self, multiplier: f64) -> Self {
+        self.multiplier = multiplier;
+        self
+    }
+
+    /// Randomize delays by up to this fraction in either direction. Defaults to 0.2.
+    pub fn with_jitter(mut self, jitter: f64) -> Self {
+        self.jitter = jitter.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Give up when the next retry would start after this time. Defaults to 3 minutes.
+    pub fn with_max_elapsed_time(mut self, max_elapsed_time: Option<Duration>) -> Self {
+        self.max_elapsed_time = max_elapsed_time;
+        self
+    }
+
+    /// Backoff delay after the given failed attempt, starting at 1.
+    fn backoff(&self, attempt: u32) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(i32::MAX as u32) as i32;
+        let delay = (self.initial_interval.as_secs_f64() * self.multiplier.powi(exponent))
+            .min(self.max_interval.as_secs_f64());
+        let jitter = if self.jitter > 0.0 {
+            rand::thread_rng().gen_range(-self.jitter..=self.jitter)
+        } else {
+            0.0
+        };
+        // Jitter can push a huge maximum interval past what a Duration holds
+        Duration::try_from_secs_f64((delay * (1.0 + jitter)).max(0.0)).unwrap_or(self.max_interval)
+    }
+
+    /// Run `operation` until it succeeds, fails with a non-retryable error or retries are exhausted.
+    pub(crate) async fn retry<T, F, Fut>(&self, mut operation: F) -> Result<T, InfluxError>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, InfluxError>>,
+    {
+        let start = Instant::now();
+        let mut attempts = 0;
+        loop {
+            attempts += 1;
+            let error = match operation().await {
+                Ok(value) => return Ok(value),
+                Err(error) if !error.is_retryable() => return Err(error),
+                Err(error) => error,
+            };
+
+            let delay = error
+                .retry_after()
+                .unwrap_or_else(|| self.backoff(attempts));
+            let out_of_time = self
+                .max_elapsed_time
+                .is_some_and(|max| start.elapsed().saturating_add(delay) > max);
+            if attempts >= self.max_attempts || out_of_time {
+                return Err(InfluxError::RetriesExhausted {
+                    attempts,
+                    source: Box::new(error),
+                });
+            }
+
+            tokio::time::sleep(delay).await;
+        }
+    }
+}
@@ -0,0 +1,137 @@
+//! Initial setup of a fresh server, refer: <https://docs.influxdata.com/influxdb/v2.0/api/#tag/Setup>
+use std::time::Duration;
+
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{client::Client, models::InfluxError};
+
+/// The initial user, organization and bucket of a server.
+///
+/// # Example
+/// ```no_run
+/// # async fn example() -> Result<(), influxdb_client::InfluxError> {
+/// use influxdb_client::{Client, Setup};
+///
+/// let client = Client::new("http://localhost:8086", "").unwrap();
+/// let client = client
+///     .setup(&Setup::new("admin", "tradely", "tradely").with_password("correct horse"))
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Setup {
+    username: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    password: Option<String>,
+    org: String,
+    bucket: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    retention_period_seconds: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    token: Option<String>,
+}
+
+impl Setup {
+    /// Set up a user, organization and bucket that keeps data forever.
+    pub fn new(
+        username: impl Into<String>,
+        org: impl Into<String>,
+        bucket: impl Into<String>,
+    ) -> Self {
+        Setup {
+            username: username.into(),
+            password: None,
+            org: org.into(),
+            bucket: bucket.into(),
+            retention_period_seconds: None,
+            token: None,
+        }
+    }
+
+    pub fn with_password(mut self, password: impl Into<String>) -> Self {
+        self.password = Some(password.into());
+        self
+    }
+
+    /// Delete data of the bucket older than `retention`.
+    pub fn with_retention(mut self, retention: Duration) -> Self {
+        self.retention_period_seconds = Some(retention.as_secs());
+        self
+    }
+
+    /// Use this token for the user instead of a generated one.
+    pub fn with_token(mut self, token: impl Into<String>) -> Self {
+        self.token = Some(token.into());
+        self
+    }
+}
+
+#[derive(Deserialize)]
+struct SetupAllowed {
+    allowed: bool,
+}
+
+/// Only what the new client needs, so changes to the other fields of the response cannot
+/// fail a setup that already happened on the server.
+#[derive(Deserialize)]
+struct SetupResponse {
+    org: SetupOrg,
+    bucket: SetupBucket,
+    auth: SetupAuth,
+}
+
+#[derive(Deserialize)]
+struct SetupOrg {
+    id: String,
+    name: String,
+}
+
+#[derive(Deserialize)]
+struct SetupBucket {
+    name: String,
+}
+
+#[derive(Deserialize)]
+struct SetupAuth {
+    #[serde(default)]
+    token: Option<String>,
+}
+
+impl Client {
+    /// Whether the server is fresh and can be set up.
+    pub async fn is_setup_allowed(&self) -> Result<bool, InfluxError> {
+        let request = self.api_request(Method::GET, "/api/v2/setup");
+        let allowed: SetupAllowed = self.send_json(request).await?;
+        Ok(allowed.allowed)
+    }
+
+    /// Set up a fresh server and return a client for it, using the new token, organization
+    /// and bucket.
+    ///
+    /// Fails with [`InfluxError::AlreadySetUp`] if the server has been set up before, and with
+    /// [`InfluxError::MissingToken`] if the server returned no token and none was given.
+    pub async fn setup(&self, setup: &Setup) -> Result<Client, InfluxError> {
+        if !self.is_setup_allowed().await? {
+            return Err(InfluxError::AlreadySetUp);
+        }
+
+        let request = self.api_request(Method::POST, "/api/v2/setup").json(setup);
+        let response: SetupResponse = self.send_json(request).await?;
+        // The token is always returned to the user that was just set up
+        let token = response
+            .auth
+            .token
+            .or_else(|| setup.token.clone())
+            .ok_or(InfluxError::MissingToken)?;
+
+        Ok(self
+            .clone()
+            .with_token(token)
+            .with_org(response.org.name)
+            .with_org_id(response.org.id)
+            .with_bucket(response.bucket.name))
+    }
+}
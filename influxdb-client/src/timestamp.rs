@@ -0,0 +1,318 @@
+//! Convert between time types and timestamps of a given precision.
+use std::convert::TryFrom;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::csv::{parse_rfc3339, Cell};
+use crate::models::{InfluxError, Precision, Timestamp, Value};
+use crate::traits::{FromCell, ToTimestamp};
+
+impl Precision {
+    /// Number of nanoseconds in one unit of this precision.
+    pub(crate) fn nanos(&self) -> i128 {
+        match self {
+            Precision::NS => 1,
+            Precision::US => 1_000,
+            Precision::MS => 1_000_000,
+            Precision::S => 1_000_000_000,
+        }
+    }
+}
+
+/// Timestamp of `nanos` nanoseconds since the epoch, rounded down to `precision`.
+///
+/// Fails if the value does not fit into the `i64` of line protocol.
+pub(crate) fn from_nanos(nanos: i128, precision: Precision) -> Result<Timestamp, InfluxError> {
+    i64::try_from(nanos.div_euclid(precision.nanos()))
+        .map(|value| Timestamp::Precise(value, precision))
+        .map_err(|_| InfluxError::TimestampOutOfRange(precision))
+}
+
+impl Timestamp {
+    /// Timestamp of `time` in `precision`.
+    ///
+    /// Fails if `time` is out of range for `precision`, e.g. after the year 2262 in nanoseconds.
+    pub fn from_system_time(
+        time: SystemTime,
+        precision: Precision,
+    ) -> Result<Timestamp, InfluxError> {
+        from_nanos(system_time_nanos(time), precision)
+    }
+
+    /// Timestamp of `time` in `precision`, failing if it is out of range.
+    #[cfg(feature = "chrono")]
+    pub fn from_chrono<Tz: chrono::TimeZone>(
+        time: &chrono::DateTime<Tz>,
+        precision: Precision,
+    ) -> Result<Timestamp, InfluxError> {
+        from_nanos(chrono_nanos(time), precision)
+    }
+
+    /// Timestamp of `time` in `precision`, failing if it is out of range.
+    #[cfg(feature = "time")]
+    pub fn from_offset_date_time(
+        time: time::OffsetDateTime,
+        precision: Precision,
+    ) -> Result<Timestamp, InfluxError> {
+        from_nanos(time.unix_timestamp_nanos(), precision)
+    }
+
+    /// Rescale a [`Timestamp::Precise`] to `precision`, rounding down.
+    ///
+    /// Other timestamps have no unit and are returned as they are. Fails if the timestamp
+    /// overflows, or with `strict` if it would be rounded.
+    ///
+    /// # Example
+    /// ```
+    /// use influxdb_client::{Precision, Timestamp};
+    ///
+    /// let timestamp = Timestamp::Precise(1614956250123456789, Precision::NS);
+    /// assert_eq!(
+    ///     timestamp.rescale(Precision::MS, false).unwrap(),
+    ///     Timestamp::Precise(1614956250123, Precision::MS)
+    /// );
+    /// assert!(timestamp.rescale(Precision::MS, true).is_err());
+    /// ```
+    pub fn rescale(&self, precision: Precision, strict: bool) -> Result<Timestamp, InfluxError> {
+        let (value, unit) = match self {
+            Timestamp::Precise(value, unit) => (*value, *unit),
+            _ => return Ok(self.clone()),
+        };
+        let error = || InfluxError::TimestampPrecision {
+            timestamp: self.clone(),
+            precision,
+        };
+
+        let nanos = i128::from(value) * unit.nanos();
+        if strict && nanos.rem_euclid(precision.nanos()) != 0 {
+            return Err(error());
+        }
+        let value = i64::try_from(nanos.div_euclid(precision.nanos())).map_err(|_| error())?;
+        Ok(Timestamp::Precise(value, precision))
+    }
+
+    /// Parse an RFC3339 date-time, as returned by queries, into a timestamp in nanoseconds.
+    ///
+    /// # Example
+    /// ```
+    /// use influxdb_client::{Precision, Timestamp};
+    ///
+    /// let timestamp = Timestamp::parse_rfc3339("2021-03-05T14:57:30.123Z");
+    /// assert_eq!(timestamp, Some(Timestamp::Precise(1614956250123000000, Precision::NS)));
+    /// ```
+    pub fn parse_rfc3339(s: &str) -> Option<Timestamp> {
+        parse_rfc3339(s).map(|nanos| Timestamp::Precise(nanos, Precision::NS))
+    }
+
+    /// Nanoseconds since the epoch, reading integers without a unit in `precision`.
+    ///
+    /// Strings hold either an integer or an RFC3339 date-time.
+    pub(crate) fn nanos(&self, precision: Precision) -> Option<i128> {
+        let value = match self {
+            Timestamp::Precise(i, unit) => return Some(i128::from(*i) * unit.nanos()),
+            Timestamp::Int(i) => *i,
+            Timestamp::Str(s) => match s.parse::<i64>() {
+                Ok(i) => i,
+                Err(_) => return parse_rfc3339(s).map(i128::from),
+            },
+        };
+        Some(i128::from(value) * precision.nanos())
+    }
+
+    /// Format as an RFC3339 date-time in UTC, reading integers without a unit in `precision`.
+    ///
+    /// Returns `None` for strings that are no time and for years outside of 0 to 9999.
+    ///
+    /// # Example
+    /// ```
+    /// use influxdb_client::{Precision, Timestamp};
+    ///
+    /// let timestamp = Timestamp::Int(1614956250123);
+    /// assert_eq!(
+    ///     timestamp.to_rfc3339(Precision::MS).as_deref(),
+    ///     Some("2021-03-05T14:57:30.123Z")
+    /// );
+    /// ```
+    pub fn to_rfc3339(&self, precision: Precision) -> Option<String> {
+        let nanos = self.nanos(precision)?;
+        let seconds = i64::try_from(nanos.div_euclid(1_000_000_000)).ok()?;
+        let fraction = nanos.rem_euclid(1_000_000_000);
+        let (days, second_of_day) = (seconds.div_euclid(86400), seconds.rem_euclid(86400));
+
+        // Civil date from days since epoch, refer: <http://howardhinnant.github.io/date_algorithms.html#civil_from_days>
+        let z = days + 719468;
+        let era = if z >= 0 { z } else { z - 146096 } / 146097;
+        let doe = z - era * 146097;
+        let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = doy - (153 * mp + 2) / 5 + 1;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 };
+        let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+        if !(0..=9999).contains(&year) {
+            return None;
+        }
+
+        let mut s = format!(
+            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}",
+            year,
+            month,
+            day,
+            second_of_day / 3600,
+            second_of_day % 3600 / 60,
+            second_of_day % 60
+        );
+        if fraction != 0 {
+            let digits = format!("{:09}", fraction);
+            s.push('.');
+            s.push_str(digits.trim_end_matches('0'));
+        }
+        s.push('Z');
+        Some(s)
+    }
+
+    /// Convert back into a [`SystemTime`], reading integers without a unit in `precision`.
+    pub fn to_system_time(&self, precision: Precision) -> Option<SystemTime> {
+        let nanos = self.nanos(precision)?;
+        let duration = Duration::from_nanos(u64::try_from(nanos.unsigned_abs()).ok()?);
+        if nanos >= 0 {
+            UNIX_EPOCH.checked_add(duration)
+        } else {
+            UNIX_EPOCH.checked_sub(duration)
+        }
+    }
+
+    /// Convert back into a `chrono::DateTime`, reading integers without a unit in `precision`.
+    #[cfg(feature = "chrono")]
+    pub fn to_chrono(&self, precision: Precision) -> Option<chrono::DateTime<chrono::Utc>> {
+        let nanos = self.nanos(precision)?;
+        let seconds = i64::try_from(nanos.div_euclid(1_000_000_000)).ok()?;
+        chrono::DateTime::from_timestamp(seconds, nanos.rem_euclid(1_000_000_000) as u32)
+    }
+
+    /// Convert back into a `time::OffsetDateTime`, reading integers without a unit in `precision`.
+    #[cfg(feature = "time")]
+    pub fn to_offset_date_time(&self, precision: Precision) -> Option<time::OffsetDateTime> {
+        time::OffsetDateTime::from_unix_timestamp_nanos(self.nanos(precision)?).ok()
+    }
+}
+
+fn system_time_nanos(time: SystemTime) -> i128 {
+    match time.duration_since(UNIX_EPOCH) {
+        Ok(since) => since.as_nanos() as i128,
+        Err(before) => -(before.duration().as_nanos() as i128),
+    }
+}
+
+#[cfg(feature = "chrono")]
+fn chrono_nanos<Tz: chrono::TimeZone>(time: &chrono::DateTime<Tz>) -> i128 {
+    i128::from(time.timestamp()) * 1_000_000_000 + i128::from(time.timestamp_subsec_nanos())
+}
+
+/// Converts to nanoseconds, which covers the years 1677 to 2262.
+impl TryFrom<SystemTime> for Timestamp {
+    type Error = InfluxError;
+
+    fn try_from(time: SystemTime) -> Result<Timestamp, InfluxError> {
+        Timestamp::from_system_time(time, Precision::NS)
+    }
+}
+
+/// Converts to nanoseconds, which covers the years 1677 to 2262.
+#[cfg(feature = "chrono")]
+impl<Tz: chrono::TimeZone> TryFrom<chrono::DateTime<Tz>> for Timestamp {
+    type Error = InfluxError;
+
+    fn try_from(time: chrono::DateTime<Tz>) -> Result<Timestamp, InfluxError> {
+        Timestamp::from_chrono(&time, Precision::NS)
+    }
+}
+
+/// Converts to nanoseconds, which covers the years 1677 to 2262.
+#[cfg(feature = "time")]
+impl TryFrom<time::OffsetDateTime> for Timestamp {
+    type Error = InfluxError;
+
+    fn try_from(time: time::OffsetDateTime) -> Result<Timestamp, InfluxError> {
+        Timestamp::from_offset_date_time(time, Precision::NS)
+    }
+}
+
+/// Time of a query result cell, either a `dateTime` column or a string holding an RFC3339 date-time.
+fn cell_timestamp(cell: &Cell) -> Option<Timestamp> {
+    match cell {
+        Cell::Time(t) => Some(t.clone()),
+        Cell::Value(Value::Str(s)) => Timestamp::parse_rfc3339(s),
+        _ => None,
+    }
+}
+
+impl FromCell for SystemTime {
+    fn from_cell(cell: &Cell) -> Option<Self> {
+        cell_timestamp(cell)?.to_system_time(Precision::NS)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl FromCell for chrono::DateTime<chrono::Utc> {
+    fn from_cell(cell: &Cell) -> Option<Self> {
+        cell_timestamp(cell)?.to_chrono(Precision::NS)
+    }
+}
+
+#[cfg(feature = "time")]
+impl FromCell for time::OffsetDateTime {
+    fn from_cell(cell: &Cell) -> Option<Self> {
+        cell_timestamp(cell)?.to_offset_date_time(Precision::NS)
+    }
+}
+
+impl ToTimestamp for Timestamp {
+    fn to_timestamp(&self, _precision: Precision) -> Result<Option<Timestamp>, InfluxError> {
+        Ok(Some(self.clone()))
+    }
+}
+
+impl<T: ToTimestamp> ToTimestamp for Option<T> {
+    fn to_timestamp(&self, precision: Precision) -> Result<Option<Timestamp>, InfluxError> {
+        match self {
+            Some(t) => t.to_timestamp(precision),
+            None => Ok(None),
+        }
+    }
+}
+
+/// Integers are taken to already be in the requested precision.
+impl ToTimestamp for i64 {
+    fn to_timestamp(&self, _precision: Precision) -> Result<Option<Timestamp>, InfluxError> {
+        Ok(Some(Timestamp::Int(*self)))
+    }
+}
+
+/// Integers are taken to already be in the requested precision.
+impl ToTimestamp for u64 {
+    fn to_timestamp(&self, precision: Precision) -> Result<Option<Timestamp>, InfluxError> {
+        i64::try_from(*self)
+            .map(|value| Some(Timestamp::Int(value)))
+            .map_err(|_| InfluxError::TimestampOutOfRange(precision))
+    }
+}
+
+impl ToTimestamp for SystemTime {
+    fn to_timestamp(&self, precision: Precision) -> Result<Option<Timestamp>, InfluxError> {
+        Timestamp::from_system_time(*self, precision).map(Some)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl<Tz: chrono::TimeZone> ToTimestamp for chrono::DateTime<Tz> {
+    fn to_timestamp(&self, precision: Precision) -> Result<Option<Timestamp>, InfluxError> {
+        Timestamp::from_chrono(self, precision).map(Some)
+    }
+}
+
+#[cfg(feature = "time")]
+impl ToTimestamp for time::OffsetDateTime {
+    fn to_timestamp(&self, precision: Precision) -> Result<Option<Timestamp>, InfluxError> {
+        Timestamp::from_offset_date_time(*self, precision).map(Some)
+    }
+}
@@ -1,6 +1,63 @@
-use crate::models::Timestamp;
+use crate::csv::{Cell, Record, RecordError};
+use crate::models::{InfluxError, Precision, Timestamp};
 
 pub trait PointSerialize {
     fn serialize(&self) -> String;
+    /// Serialize with `timestamp`, or the timestamp of the point itself if it is `None`.
+    ///
+    /// Derived implementations leave out a timestamp field that is out of range for nanoseconds,
+    /// use [`PointSerialize::serialize_with_precision`] to fail instead.
     fn serialize_with_timestamp(&self, timestamp: Option<Timestamp>) -> String;
+
+    /// Like [`PointSerialize::serialize_with_timestamp`], with the timestamp rescaled to `precision`.
+    ///
+    /// Only [`Timestamp::Precise`] is rescaled. Overflowing timestamps fail, and with `strict`
+    /// so do timestamps that would lose digits. Types holding their own timestamp override this
+    /// to rescale that one too.
+    fn serialize_with_precision(
+        &self,
+        timestamp: Option<Timestamp>,
+        precision: Precision,
+        strict: bool,
+    ) -> Result<String, InfluxError> {
+        let timestamp = match timestamp {
+            Some(timestamp) => Some(timestamp.rescale(precision, strict)?),
+            None => None,
+        };
+        Ok(self.serialize_with_timestamp(timestamp))
+    }
+
+    /// Check that the point can be written, e.g. that it has at least one field.
+    fn validate(&self) -> Result<(), InfluxError> {
+        Ok(())
+    }
+}
+
+/// Convert a value into a timestamp of the given precision.
+///
+/// Implemented for the types accepted by `#[point(timestamp)]` of the derive:
+/// [`Timestamp`], `i64`, `u64`, [`std::time::SystemTime`], `Option` of any of those,
+/// and `chrono::DateTime` and `time::OffsetDateTime` with the `chrono` and `time` features.
+pub trait ToTimestamp {
+    /// Returns `None` if there is no timestamp, e.g. for an empty `Option`, and fails if the
+    /// time is out of range for `precision`.
+    fn to_timestamp(&self, precision: Precision) -> Result<Option<Timestamp>, InfluxError>;
+}
+
+/// Build a value from a record of a query result.
+///
+/// Use `#[derive(FromRecord)]` from [`crate::derives`] to implement this for structs.
+pub trait FromRecord: Sized {
+    fn from_record(record: Record) -> Result<Self, RecordError>;
+}
+
+/// Convert a single cell of a query result into a Rust value.
+pub trait FromCell: Sized {
+    /// Returns `None` if the cell does not hold a value of this type.
+    fn from_cell(cell: &Cell) -> Option<Self>;
+
+    /// Value to use when the column is missing. Only optional types have one.
+    fn from_missing() -> Option<Self> {
+        None
+    }
 }
@@ -0,0 +1,212 @@
+//! Write and query through the 1.x compatibility API, refer: <https://docs.influxdata.com/influxdb/v2.0/reference/api/influxdb-1x/>
+use std::collections::HashMap;
+
+use reqwest::Method;
+use serde::Deserialize;
+
+use crate::{
+    client::Client,
+    models::{InfluxError, Point, Precision, Timestamp, TimestampOptions, Value},
+    traits::PointSerialize,
+};
+
+/// Series of an InfluxQL query result.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Series {
+    pub name: String,
+    /// Tags grouped by with `GROUP BY`.
+    #[serde(default)]
+    pub tags: HashMap<String, String>,
+    pub columns: Vec<String>,
+    #[serde(default)]
+    pub values: Vec<Vec<serde_json::Value>>,
+}
+
+impl Series {
+    /// Convert the rows into points of the measurement `name`, e.g. to copy them into a bucket.
+    ///
+    /// The `time` column becomes the timestamp, read as nanoseconds if it is a number. The tags
+    /// of the series become tags and all other non-null columns become fields. Rows without
+    /// fields are skipped.
+    ///
+    /// Numbers become float fields, as JSON does not tell whole floats apart from integers.
+    /// Use [`Series::to_points_with_integers`] for integer columns.
+    pub fn to_points(&self) -> Vec<Point> {
+        self.to_points_with_integers(&[])
+    }
+
+    /// Like [`Series::to_points`], with whole numbers of `integer_columns` as integer fields.
+    ///
+    /// # Example
+    /// ```
+    /// use influxdb_client::{Series, Value};
+    ///
+    /// let series: Series = serde_json::from_str(
+    ///     r#"{"name": "cpu", "columns": ["time", "count", "mean"], "values": [["2021-03-05T14:57:30Z", 3, 2]]}"#,
+    /// )
+    /// .unwrap();
+    /// let points = series.to_points_with_integers(&["count"]);
+    /// assert_eq!(
+    ///     points[0].fields,
+    ///     vec![("count".to_string(), Value::Int(3)), ("mean".to_string(), Value::Float(2.0))]
+    /// );
+    /// ```
+    pub fn to_points_with_integers(&self, integer_columns: &[&str]) -> Vec<Point> {
+        let mut tags: Vec<_> = self.tags.iter().collect();
+        tags.sort();
+
+        self.values
+            .iter()
+            .filter_map(|row| {
+                let mut point = Point::new(&self.name);
+                point.tags = tags
+                    .iter()
+                    .map(|(k, v)| (k.to_string(), v.to_string()))
+                    .collect();
+                for (column, value) in self.columns.iter().zip(row) {
+                    if column == "time" {
+                        point.timestamp = json_timestamp(value);
+                    } else if let Some(value) =
+                        json_value(value, integer_columns.contains(&column.as_str()))
+                    {
+                        point.fields.push((column.clone(), value));
+                    }
+                }
+                Some(point).filter(|p| !p.fields.is_empty())
+            })
+            .collect()
+    }
+}
+
+fn json_timestamp(value: &serde_json::Value) -> Option<Timestamp> {
+    match value {
+        serde_json::Value::String(s) => Timestamp::parse_rfc3339(s),
+        serde_json::Value::Number(n) => n.as_i64().map(|n| Timestamp::Precise(n, Precision::NS)),
+        _ => None,
+    }
+}
+
+fn json_value(value: &serde_json::Value, integer: bool) -> Option<Value> {
+    match value {
+        serde_json::Value::String(s) => Some(Value::Str(s.clone())),
+        serde_json::Value::Bool(b) => Some(Value::Bool(*b)),
+        serde_json::Value::Number(n) if integer => n
+            .as_i64()
+            .map(Value::Int)
+            .or_else(|| n.as_u64().map(Value::UInt))
+            .or_else(|| n.as_f64().map(Value::Float)),
+        serde_json::Value::Number(n) => n.as_f64().map(Value::Float),
+        _ => None,
+    }
+}
+
+#[derive(Deserialize)]
+struct QueryResponse {
+    #[serde(default)]
+    results: Vec<StatementResult>,
+    #[serde(default)]
+    error: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct StatementResult {
+    #[serde(default)]
+    series: Vec<Series>,
+    #[serde(default)]
+    error: Option<String>,
+}
+
+impl Client {
+    /// Write points to a 1.x database and retention policy, mapped onto a bucket with
+    /// [`Client::dbrps`]. The default retention policy of the database is used without one.
+    ///
+    /// Timestamps are written in the precision of the client, like [`Client::insert_points`].
+    ///
+    /// # Example
+    /// ```no_run
+    /// # async fn example() -> Result<(), influxdb_client::InfluxError> {
+    /// use influxdb_client::{Client, Point, TimestampOptions};
+    /// let client = Client::new("http://localhost:8086", "token").unwrap();
+    /// let points = vec![Point::new("cpu").tag("host", "a").field("usage", 1.5)];
+    /// client
+    ///     .insert_points_v1("telegraf", Some("autogen"), &points, TimestampOptions::None)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn insert_points_v1<'a, I: IntoIterator<Item = &'a (impl PointSerialize + 'a)>>(
+        &self,
+        database: &str,
+        retention_policy: Option<&str>,
+        points: I,
+        options: TimestampOptions,
+    ) -> Result<(), InfluxError> {
+        let body = self.serialize_points(points, &options)?;
+        let query = v1_query(
+            database,
+            retention_policy,
+            Some(self.precision().to_string()),
+        );
+        self.write_body_with(body, || {
+            self.api_request(Method::POST, "/write").query(&query)
+        })
+        .await
+    }
+
+    /// Run an InfluxQL query against a 1.x database and retention policy, mapped onto a
+    /// bucket with [`Client::dbrps`].
+    ///
+    /// Returns the series of all statements in order. Times are RFC3339 strings.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # async fn example() -> Result<(), influxdb_client::InfluxError> {
+    /// use influxdb_client::Client;
+    /// let client = Client::new("http://localhost:8086", "token").unwrap();
+    /// let series = client
+    ///     .query_v1("telegraf", None, "SELECT mean(usage) FROM cpu WHERE time > now() - 1h GROUP BY host")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn query_v1(
+        &self,
+        database: &str,
+        retention_policy: Option<&str>,
+        influxql: &str,
+    ) -> Result<Vec<Series>, InfluxError> {
+        let request = self
+            .api_request(Method::POST, "/query")
+            .header("Accept", "application/json")
+            .query(&v1_query(database, retention_policy, None))
+            .form(&[("q", influxql)]);
+        let response: QueryResponse = self.send_json(request).await?;
+
+        if let Some(error) = response.error {
+            return Err(InfluxError::InfluxQl(error));
+        }
+        let mut series = Vec::new();
+        for result in response.results {
+            if let Some(error) = result.error {
+                return Err(InfluxError::InfluxQl(error));
+            }
+            series.extend(result.series);
+        }
+        Ok(series)
+    }
+}
+
+fn v1_query(
+    database: &str,
+    retention_policy: Option<&str>,
+    precision: Option<String>,
+) -> Vec<(&'static str, String)> {
+    let mut query = vec![("db", database.to_string())];
+    if let Some(retention_policy) = retention_policy {
+        query.push(("rp", retention_policy.to_string()));
+    }
+    if let Some(precision) = precision {
+        query.push(("precision", precision));
+    }
+    query
+}
@@ -0,0 +1,141 @@
+use influxdb_client::api::{
+    Action, Authorization, NewAuthorization, Permission, Resource, ResourceType, Status,
+};
+use influxdb_client::{Client, InfluxError};
+
+use serde_json::json;
+
+const AUTHORIZATION: &str = r#"{
+    "id": "06c9d4b2e1a2f000",
+    "token": "secret-token",
+    "status": "active",
+    "description": "ingest service",
+    "orgID": "168f31904923e853",
+    "org": "tradely",
+    "userID": "0a1",
+    "user": "anders",
+    "permissions": [
+        {"action": "write", "resource": {"type": "buckets", "id": "0b3a1fd4d2a0e8a1", "orgID": "168f31904923e853"}},
+        {"action": "read", "resource": {"type": "dashboards", "orgID": "168f31904923e853", "org": "tradely"}}
+    ],
+    "links": {}
+}"#;
+
+fn client() -> Client {
+    Client::new(mockito::server_url(), "TEST_API_KEY")
+        .unwrap()
+        .with_org_id("168f31904923e853")
+}
+
+#[test]
+fn test_authorizations_create_write_only_token() {
+    let mock = mockito::mock("POST", "/api/v2/authorizations")
+        .match_body(mockito::Matcher::Json(json!({
+            "orgID": "168f31904923e853",
+            "description": "ingest service",
+            "permissions": [
+                {"action": "write", "resource": {"type": "buckets", "id": "0b3a1fd4d2a0e8a1", "orgID": "168f31904923e853"}},
+                {"action": "read", "resource": {"type": "dashboards", "orgID": "168f31904923e853"}},
+            ],
+        })))
+        .with_status(201)
+        .with_body(AUTHORIZATION)
+        .expect(1)
+        .create();
+
+    let new_authorization = NewAuthorization::new()
+        .with_description("ingest service")
+        .with_permission(Permission::write(Resource::bucket("0b3a1fd4d2a0e8a1")))
+        .with_permission(Permission::read(Resource::all(ResourceType::Dashboards)));
+    let authorization =
+        tokio_test::block_on(client().authorizations().create(&new_authorization)).unwrap();
+
+    assert_eq!(authorization.token.as_deref(), Some("secret-token"));
+    assert_eq!(authorization.status, Status::Active);
+    assert_eq!(authorization.permissions[0].action, Action::Write);
+    assert_eq!(
+        authorization.permissions[0].resource.kind,
+        ResourceType::Buckets
+    );
+    assert_eq!(
+        authorization.permissions[1].resource.org.as_deref(),
+        Some("tradely")
+    );
+
+    mock.assert();
+}
+
+#[test]
+fn test_authorizations_unknown_resource_type() {
+    let authorization = AUTHORIZATION.replace("\"dashboards\"", "\"notebooks\"");
+    let authorization: Authorization = serde_json::from_str(&authorization).unwrap();
+    let resource = &authorization.permissions[1].resource;
+
+    assert_eq!(resource.kind, ResourceType::Other("notebooks".to_string()));
+    assert_eq!(
+        serde_json::to_value(resource).unwrap()["type"],
+        json!("notebooks")
+    );
+    assert_eq!(
+        serde_json::to_value(Resource::all(ResourceType::NotificationRules)).unwrap()["type"],
+        json!("notificationRules")
+    );
+}
+
+#[test]
+fn test_authorizations_list_org_lookup_fails() {
+    let orgs = mockito::mock("GET", "/api/v2/orgs")
+        .match_query(mockito::Matcher::UrlEncoded("org".into(), "tradely".into()))
+        .with_status(500)
+        .with_body(r#"{"code": "internal error", "message": "boom"}"#)
+        .expect(1)
+        .create();
+
+    let client = Client::new(mockito::server_url(), "TEST_API_KEY")
+        .unwrap()
+        .with_org("tradely");
+    let result = tokio_test::block_on(client.authorizations().list());
+
+    assert!(matches!(result, Err(InfluxError::ServerError(_))));
+
+    orgs.assert();
+}
+
+#[test]
+fn test_authorizations_list_deactivate_delete() {
+    let list = mockito::mock("GET", "/api/v2/authorizations")
+        .match_query(mockito::Matcher::UrlEncoded(
+            "orgID".into(),
+            "168f31904923e853".into(),
+        ))
+        .with_status(200)
+        .with_body(format!(r#"{{"authorizations": [{}]}}"#, AUTHORIZATION))
+        .expect(1)
+        .create();
+    let deactivate = mockito::mock("PATCH", "/api/v2/authorizations/06c9d4b2e1a2f000")
+        .match_body(mockito::Matcher::Json(json!({"status": "inactive"})))
+        .with_status(200)
+        .with_body(AUTHORIZATION.replace("\"active\"", "\"inactive\""))
+        .expect(1)
+        .create();
+    let delete = mockito::mock("DELETE", "/api/v2/authorizations/06c9d4b2e1a2f000")
+        .with_status(204)
+        .expect(1)
+        .create();
+
+    let client = client();
+    let authorizations = client.authorizations();
+    tokio_test::block_on(async {
+        let listed = authorizations.list().await.unwrap();
+        assert_eq!(listed.len(), 1);
+
+        let deactivated = authorizations.deactivate(&listed[0].id).await.unwrap();
+        assert_eq!(deactivated.status, Status::Inactive);
+
+        authorizations.delete(&listed[0].id).await.unwrap();
+    });
+
+    list.assert();
+    deactivate.assert();
+    delete.assert();
+}
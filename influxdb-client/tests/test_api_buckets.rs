@@ -0,0 +1,169 @@
+use std::time::Duration;
+
+use influxdb_client::api::{BucketUpdate, ListOptions, NewBucket, RetentionRule};
+use influxdb_client::{Client, InfluxError};
+
+use serde_json::json;
+
+const BUCKET: &str = r#"{
+    "id": "0b3a1fd4d2a0e8a1",
+    "orgID": "168f31904923e853",
+    "type": "user",
+    "name": "customer-42",
+    "description": "Customer 42",
+    "retentionRules": [{"type": "expire", "everySeconds": 86400, "shardGroupDurationSeconds": 3600}],
+    "createdAt": "2021-03-05T14:57:30Z",
+    "updatedAt": "2021-03-05T14:57:30Z",
+    "links": {"self": "/api/v2/buckets/0b3a1fd4d2a0e8a1"}
+}"#;
+
+fn client() -> Client {
+    Client::new(mockito::server_url(), "TEST_API_KEY")
+        .unwrap()
+        .with_org_id("168f31904923e853")
+}
+
+#[test]
+fn test_buckets_create() {
+    let mock = mockito::mock("POST", "/api/v2/buckets")
+        .match_header("authorization", "Token TEST_API_KEY")
+        .match_body(mockito::Matcher::Json(json!({
+            "orgID": "168f31904923e853",
+            "name": "customer-42",
+            "description": "Customer 42",
+            "retentionRules": [{"type": "expire", "everySeconds": 86400}],
+        })))
+        .with_status(201)
+        .with_body(BUCKET)
+        .expect(1)
+        .create();
+
+    let new_bucket = NewBucket::new("customer-42")
+        .with_description("Customer 42")
+        .with_retention(RetentionRule::expire(Duration::from_secs(86400)));
+    let bucket = tokio_test::block_on(client().buckets().create(&new_bucket)).unwrap();
+
+    assert_eq!(bucket.id, "0b3a1fd4d2a0e8a1");
+    assert_eq!(bucket.org_id, "168f31904923e853");
+    assert_eq!(bucket.retention_rules[0].every_seconds, 86400);
+    assert_eq!(
+        bucket.retention_rules[0].shard_group_duration_seconds,
+        Some(3600)
+    );
+
+    mock.assert();
+}
+
+#[test]
+fn test_buckets_create_without_org() {
+    let client = Client::new("http://localhost:8086", "TEST_API_KEY").unwrap();
+
+    let result = tokio_test::block_on(client.buckets().create(&NewBucket::new("customer-42")));
+
+    assert!(matches!(result, Err(InfluxError::MissingOrg)));
+}
+
+#[test]
+fn test_buckets_list() {
+    let mock = mockito::mock("GET", "/api/v2/buckets")
+        .match_query(mockito::Matcher::AllOf(vec![
+            mockito::Matcher::UrlEncoded("name".into(), "customer-42".into()),
+            mockito::Matcher::UrlEncoded("limit".into(), "10".into()),
+            mockito::Matcher::UrlEncoded("offset".into(), "20".into()),
+            mockito::Matcher::UrlEncoded("orgID".into(), "168f31904923e853".into()),
+        ]))
+        .with_status(200)
+        .with_body(format!(r#"{{"links": {{}}, "buckets": [{}]}}"#, BUCKET))
+        .expect(1)
+        .create();
+
+    let options = ListOptions::default()
+        .with_name("customer-42")
+        .with_limit(10)
+        .with_offset(20);
+    let buckets = tokio_test::block_on(client().buckets().list(&options)).unwrap();
+
+    assert_eq!(buckets.len(), 1);
+    assert_eq!(buckets[0].name, "customer-42");
+
+    mock.assert();
+}
+
+#[test]
+fn test_buckets_list_org_lookup_fails() {
+    let orgs = mockito::mock("GET", "/api/v2/orgs")
+        .match_query(mockito::Matcher::UrlEncoded("org".into(), "tradely".into()))
+        .with_status(401)
+        .with_body(r#"{"code": "unauthorized", "message": "unauthorized access"}"#)
+        .expect(1)
+        .create();
+    let buckets = mockito::mock("GET", "/api/v2/buckets")
+        .match_query(mockito::Matcher::Any)
+        .expect(0)
+        .create();
+
+    let client = Client::new(mockito::server_url(), "TEST_API_KEY")
+        .unwrap()
+        .with_org("tradely");
+    let result = tokio_test::block_on(client.buckets().list(&ListOptions::default()));
+
+    assert!(matches!(result, Err(InfluxError::InvalidCredentials(_))));
+
+    orgs.assert();
+    buckets.assert();
+}
+
+#[test]
+fn test_buckets_get_update_delete() {
+    let get = mockito::mock("GET", "/api/v2/buckets/0b3a1fd4d2a0e8a1")
+        .with_status(200)
+        .with_body(BUCKET)
+        .expect(1)
+        .create();
+    let update = mockito::mock("PATCH", "/api/v2/buckets/0b3a1fd4d2a0e8a1")
+        .match_body(mockito::Matcher::Json(json!({
+            "description": "Former customer",
+            "retentionRules": [],
+        })))
+        .with_status(200)
+        .with_body(BUCKET)
+        .expect(1)
+        .create();
+    let delete = mockito::mock("DELETE", "/api/v2/buckets/0b3a1fd4d2a0e8a1")
+        .with_status(204)
+        .expect(1)
+        .create();
+
+    let client = client();
+    let buckets = client.buckets();
+    tokio_test::block_on(async {
+        let bucket = buckets.get("0b3a1fd4d2a0e8a1").await.unwrap();
+        let update = BucketUpdate::default()
+            .with_description("Former customer")
+            .with_retention_rules(vec![]);
+        buckets.update(&bucket.id, &update).await.unwrap();
+        buckets.delete(&bucket.id).await.unwrap();
+    });
+
+    get.assert();
+    update.assert();
+    delete.assert();
+}
+
+#[test]
+fn test_buckets_not_found() {
+    let mock = mockito::mock("GET", "/api/v2/buckets/missing")
+        .with_status(404)
+        .with_body(r#"{"code": "not found", "message": "bucket not found"}"#)
+        .expect(1)
+        .create();
+
+    let result = tokio_test::block_on(client().buckets().get("missing"));
+
+    match result {
+        Err(InfluxError::NotFound(error)) => assert_eq!(error.message, "bucket not found"),
+        other => panic!("unexpected result: {:?}", other),
+    }
+
+    mock.assert();
+}
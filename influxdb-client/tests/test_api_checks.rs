@@ -0,0 +1,380 @@
+use influxdb_client::api::{
+    Check, CheckKind, EndpointKind, HttpAuth, HttpMethod, Level, ListOptions, NotificationEndpoint,
+    NotificationRule, RuleKind, Status, StatusRule, TagRule, Threshold,
+};
+use influxdb_client::Client;
+
+use serde_json::json;
+
+const CHECK: &str = r#"{
+    "id": "077a3c1d6e4f1000",
+    "orgID": "168f31904923e853",
+    "name": "High CPU",
+    "status": "active",
+    "query": {"text": "from(bucket: \"telegraf\")", "editMode": "advanced"},
+    "every": "1m",
+    "statusMessageTemplate": "CPU is ${r._level}",
+    "type": "threshold",
+    "thresholds": [
+        {"type": "greater", "value": 90, "level": "CRIT", "allValues": false},
+        {"type": "range", "min": 50, "max": 90, "within": true, "level": "WARN"}
+    ],
+    "labels": [],
+    "links": {}
+}"#;
+
+fn client() -> Client {
+    Client::new(mockito::server_url(), "TEST_API_KEY")
+        .unwrap()
+        .with_org_id("168f31904923e853")
+}
+
+#[test]
+fn test_checks_create_threshold() {
+    let mock = mockito::mock("POST", "/api/v2/checks")
+        .match_body(mockito::Matcher::Json(json!({
+            "orgID": "168f31904923e853",
+            "name": "High CPU",
+            "query": {"text": "from(bucket: \"telegraf\")"},
+            "every": "1m",
+            "statusMessageTemplate": "CPU is ${r._level}",
+            "type": "threshold",
+            "thresholds": [
+                {"type": "greater", "value": 90.0, "level": "CRIT", "allValues": false},
+                {"type": "range", "min": 50.0, "max": 90.0, "within": true, "level": "WARN", "allValues": false},
+            ],
+        })))
+        .with_status(201)
+        .with_body(CHECK)
+        .expect(1)
+        .create();
+
+    let check = Check::new(
+        "High CPU",
+        r#"from(bucket: "telegraf")"#,
+        CheckKind::Threshold {
+            thresholds: vec![
+                Threshold::Greater {
+                    value: 90.0,
+                    level: Level::Crit,
+                    all_values: false,
+                },
+                Threshold::Range {
+                    min: 50.0,
+                    max: 90.0,
+                    within: true,
+                    level: Level::Warn,
+                    all_values: false,
+                },
+            ],
+        },
+    )
+    .with_every("1m")
+    .with_status_message_template("CPU is ${r._level}");
+    let created = tokio_test::block_on(client().checks().create(&check)).unwrap();
+
+    assert_eq!(created.id.as_deref(), Some("077a3c1d6e4f1000"));
+    assert_eq!(created.status, Some(Status::Active));
+    assert_eq!(created.kind, check.kind);
+
+    mock.assert();
+}
+
+#[test]
+fn test_checks_deadman_list_update_delete() {
+    // The name filter is applied on the client
+    let list = mockito::mock("GET", "/api/v2/checks")
+        .match_query(mockito::Matcher::Exact(
+            "limit=5&orgID=168f31904923e853".into(),
+        ))
+        .with_status(200)
+        .with_body(
+            r#"{"checks": [{
+            "id": "077a3c1d6e4f2000",
+            "orgID": "168f31904923e853",
+            "name": "Sensor silent",
+            "status": "active",
+            "query": {"text": "from(bucket: \"sensors\")"},
+            "every": "1m",
+            "type": "deadman",
+            "timeSince": "90s",
+            "staleTime": "10m",
+            "reportZero": false,
+            "level": "CRIT"
+        }]}"#,
+        )
+        .expect(2)
+        .create();
+    let update = mockito::mock("PUT", "/api/v2/checks/077a3c1d6e4f2000")
+        .match_body(mockito::Matcher::PartialJson(json!({
+            "status": "inactive",
+            "type": "deadman",
+            "timeSince": "5m",
+        })))
+        .with_status(200)
+        .with_body(CHECK)
+        .expect(1)
+        .create();
+    let delete = mockito::mock("DELETE", "/api/v2/checks/077a3c1d6e4f2000")
+        .with_status(204)
+        .expect(1)
+        .create();
+
+    let client = client();
+    let checks = client.checks();
+    tokio_test::block_on(async {
+        let options = ListOptions::default().with_limit(5);
+        let listed = checks
+            .list(&options.clone().with_name("Sensor silent"))
+            .await
+            .unwrap();
+        assert_eq!(listed.len(), 1);
+        let others = checks
+            .list(&options.with_name("Sensor loud"))
+            .await
+            .unwrap();
+        assert!(others.is_empty());
+        let check = listed[0].clone();
+        assert_eq!(
+            check.kind,
+            CheckKind::Deadman {
+                time_since: "90s".to_string(),
+                stale_time: Some("10m".to_string()),
+                report_zero: false,
+                level: Level::Crit,
+            }
+        );
+
+        let mut check = check.with_status(Status::Inactive);
+        if let CheckKind::Deadman { time_since, .. } = &mut check.kind {
+            *time_since = "5m".to_string();
+        }
+        let id = check.id.clone().unwrap();
+        checks.update(&id, &check).await.unwrap();
+        checks.delete(&id).await.unwrap();
+    });
+
+    list.assert();
+    update.assert();
+    delete.assert();
+}
+
+#[test]
+fn test_notification_endpoints() {
+    let create = mockito::mock("POST", "/api/v2/notificationEndpoints")
+        .match_body(mockito::Matcher::Json(json!({
+            "orgID": "168f31904923e853",
+            "name": "ops",
+            "type": "http",
+            "url": "https://example.com/alerts",
+            "method": "POST",
+            "authMethod": "bearer",
+            "token": "secret",
+        })))
+        .with_status(201)
+        .with_body(
+            r#"{
+            "id": "077a4e0b1c2d3000",
+            "orgID": "168f31904923e853",
+            "name": "ops",
+            "status": "active",
+            "type": "http",
+            "url": "https://example.com/alerts",
+            "method": "POST",
+            "authMethod": "bearer",
+            "token": "secret: 077a4e0b1c2d3000-token",
+            "links": {}
+        }"#,
+        )
+        .expect(1)
+        .create();
+    let list = mockito::mock("GET", "/api/v2/notificationEndpoints")
+        .match_query(mockito::Matcher::UrlEncoded(
+            "orgID".into(),
+            "168f31904923e853".into(),
+        ))
+        .with_status(200)
+        .with_body(
+            r#"{"notificationEndpoints": [{
+            "id": "077a4e0b1c2d4000",
+            "orgID": "168f31904923e853",
+            "name": "team",
+            "status": "inactive",
+            "type": "slack",
+            "url": "https://hooks.slack.com/services/X"
+        }]}"#,
+        )
+        .expect(1)
+        .create();
+    let delete = mockito::mock("DELETE", "/api/v2/notificationEndpoints/077a4e0b1c2d4000")
+        .with_status(204)
+        .expect(1)
+        .create();
+
+    let client = client();
+    let endpoints = client.notification_endpoints();
+    tokio_test::block_on(async {
+        let endpoint = NotificationEndpoint::new(
+            "ops",
+            EndpointKind::http_bearer("https://example.com/alerts", "secret"),
+        );
+        let created = endpoints.create(&endpoint).await.unwrap();
+        match created.kind {
+            EndpointKind::Http {
+                method,
+                auth_method,
+                ..
+            } => {
+                assert_eq!(method, HttpMethod::Post);
+                assert_eq!(auth_method, HttpAuth::Bearer);
+            }
+            kind => panic!("unexpected endpoint {:?}", kind),
+        }
+
+        let listed = endpoints.list(&ListOptions::default()).await.unwrap();
+        assert_eq!(listed[0].status, Some(Status::Inactive));
+        assert_eq!(
+            listed[0].kind,
+            EndpointKind::slack("https://hooks.slack.com/services/X")
+        );
+        endpoints
+            .delete(listed[0].id.as_deref().unwrap())
+            .await
+            .unwrap();
+    });
+
+    create.assert();
+    list.assert();
+    delete.assert();
+}
+
+#[test]
+fn test_notification_rules() {
+    let create = mockito::mock("POST", "/api/v2/notificationRules")
+        .match_body(mockito::Matcher::Json(json!({
+            "orgID": "168f31904923e853",
+            "name": "page on crit",
+            "status": "active",
+            "endpointID": "077a4e0b1c2d4000",
+            "every": "1m",
+            "statusRules": [{"currentLevel": "CRIT", "previousLevel": "OK"}],
+            "tagRules": [{"key": "host", "value": "db1", "operator": "equal"}],
+            "type": "slack",
+            "channel": "#ops",
+            "messageTemplate": "${r._message}",
+        })))
+        .with_status(201)
+        .with_body(
+            r##"{
+            "id": "077a5f0b1c2d1000",
+            "orgID": "168f31904923e853",
+            "name": "page on crit",
+            "status": "active",
+            "endpointID": "077a4e0b1c2d4000",
+            "every": "1m",
+            "statusRules": [{"currentLevel": "CRIT", "previousLevel": "OK"}],
+            "tagRules": [{"key": "host", "value": "db1", "operator": "equal"}],
+            "type": "slack",
+            "channel": "#ops",
+            "messageTemplate": "${r._message}"
+        }"##,
+        )
+        .expect(1)
+        .create();
+    let get = mockito::mock("GET", "/api/v2/notificationRules/077a5f0b1c2d2000")
+        .with_status(200)
+        .with_body(
+            r#"{
+            "id": "077a5f0b1c2d2000",
+            "orgID": "168f31904923e853",
+            "name": "forward",
+            "status": "active",
+            "endpointID": "077a4e0b1c2d3000",
+            "every": "10m",
+            "type": "http"
+        }"#,
+        )
+        .expect(1)
+        .create();
+
+    let client = client();
+    let rules = client.notification_rules();
+    tokio_test::block_on(async {
+        let rule = NotificationRule::new(
+            "page on crit",
+            "077a4e0b1c2d4000",
+            RuleKind::Slack {
+                channel: Some("#ops".to_string()),
+                message_template: "${r._message}".to_string(),
+            },
+        )
+        .with_every("1m")
+        .with_status_rule(StatusRule::change(Level::Ok, Level::Crit))
+        .with_tag_rule(TagRule::equal("host", "db1"));
+        let created = rules.create(&rule).await.unwrap();
+        assert_eq!(created.status_rules, rule.status_rules);
+
+        let rule = rules.get("077a5f0b1c2d2000").await.unwrap();
+        assert_eq!(rule.kind, RuleKind::Http);
+        assert!(rule.status_rules.is_empty());
+    });
+
+    create.assert();
+    get.assert();
+}
+
+#[test]
+fn test_alerts_unknown_types() {
+    let check: Check = serde_json::from_value(json!({
+        "id": "077a3c1d6e4f3000",
+        "name": "Custom",
+        "query": {"text": "from(bucket: \"telegraf\")"},
+        "type": "custom",
+    }))
+    .unwrap();
+    assert_eq!(
+        check.kind,
+        CheckKind::Other(json!({"type": "custom"}).as_object().unwrap().clone())
+    );
+
+    let endpoint: NotificationEndpoint = serde_json::from_value(json!({
+        "name": "pager",
+        "type": "pagerduty",
+        "clientURL": "https://example.com",
+        "routingKey": "secret: key",
+    }))
+    .unwrap();
+    assert_eq!(
+        serde_json::to_value(&endpoint.kind).unwrap(),
+        json!({"type": "pagerduty", "clientURL": "https://example.com", "routingKey": "secret: key"})
+    );
+
+    let rule: NotificationRule = serde_json::from_value(json!({
+        "name": "chat",
+        "status": "active",
+        "endpointID": "077a4e0b1c2d5000",
+        "type": "telegram",
+        "messageTemplate": "${r._message}",
+        "parseMode": "MarkdownV2",
+    }))
+    .unwrap();
+    assert!(matches!(&rule.kind, RuleKind::Other(fields) if fields["type"] == "telegram"));
+
+    // Replacing keeps the unknown type and fills in the organization like create
+    let update = mockito::mock("PUT", "/api/v2/checks/077a3c1d6e4f3000")
+        .match_body(mockito::Matcher::Json(json!({
+            "id": "077a3c1d6e4f3000",
+            "orgID": "168f31904923e853",
+            "name": "Custom",
+            "query": {"text": "from(bucket: \"telegraf\")"},
+            "type": "custom",
+        })))
+        .with_status(200)
+        .with_body(CHECK)
+        .expect(1)
+        .create();
+
+    tokio_test::block_on(client().checks().update("077a3c1d6e4f3000", &check)).unwrap();
+
+    update.assert();
+}
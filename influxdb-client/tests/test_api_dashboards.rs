@@ -0,0 +1,232 @@
+use influxdb_client::api::{CellPosition, DashboardUpdate, ListOptions, NewDashboard, View};
+use influxdb_client::{Client, InfluxError};
+
+use serde_json::json;
+
+fn client() -> Client {
+    Client::new(mockito::server_url(), "TEST_API_KEY")
+        .unwrap()
+        .with_org_id("168f31904923e853")
+}
+
+#[test]
+fn test_dashboards_crud() {
+    let create = mockito::mock("POST", "/api/v2/dashboards")
+        .match_body(mockito::Matcher::Json(json!({
+            "orgID": "168f31904923e853",
+            "name": "Servers",
+        })))
+        .with_status(201)
+        .with_body(r#"{"id": "077b3d4e5f601000", "orgID": "168f31904923e853", "name": "Servers", "cells": [], "links": {}}"#)
+        .expect(1)
+        .create();
+    let list = mockito::mock("GET", "/api/v2/dashboards")
+        .match_query(mockito::Matcher::AllOf(vec![
+            mockito::Matcher::UrlEncoded("orgID".into(), "168f31904923e853".into()),
+            mockito::Matcher::UrlEncoded("limit".into(), "5".into()),
+        ]))
+        .with_status(200)
+        .with_body(r#"{"dashboards": [{
+            "id": "077b3d4e5f601000",
+            "orgID": "168f31904923e853",
+            "name": "Servers",
+            "cells": [{"id": "077b3d4e5f602000", "x": 0, "y": 0, "w": 4, "h": 3, "viewID": "077b3d4e5f602000"}]
+        }]}"#)
+        .expect(1)
+        .create();
+    let update = mockito::mock("PATCH", "/api/v2/dashboards/077b3d4e5f601000")
+        .match_body(mockito::Matcher::Json(json!({"description": "All hosts"})))
+        .with_status(200)
+        .with_body(r#"{"id": "077b3d4e5f601000", "orgID": "168f31904923e853", "name": "Servers", "description": "All hosts"}"#)
+        .expect(1)
+        .create();
+    let delete = mockito::mock("DELETE", "/api/v2/dashboards/077b3d4e5f601000")
+        .with_status(204)
+        .expect(1)
+        .create();
+
+    let client = client();
+    let dashboards = client.dashboards();
+    tokio_test::block_on(async {
+        let dashboard = dashboards
+            .create(&NewDashboard::new("Servers"))
+            .await
+            .unwrap();
+        assert!(dashboard.cells.is_empty());
+
+        let listed = dashboards
+            .list(&ListOptions::default().with_limit(5))
+            .await
+            .unwrap();
+        assert_eq!(listed[0].cells[0].w, 4);
+        assert_eq!(
+            listed[0].cells[0].view_id.as_deref(),
+            Some("077b3d4e5f602000")
+        );
+
+        let updated = dashboards
+            .update(
+                &dashboard.id,
+                &DashboardUpdate::default().with_description("All hosts"),
+            )
+            .await
+            .unwrap();
+        assert_eq!(updated.description.as_deref(), Some("All hosts"));
+        dashboards.delete(&dashboard.id).await.unwrap();
+    });
+
+    create.assert();
+    list.assert();
+    update.assert();
+    delete.assert();
+}
+
+#[test]
+fn test_dashboards_cells() {
+    let add = mockito::mock("POST", "/api/v2/dashboards/077b3d4e5f601000/cells")
+        .match_body(mockito::Matcher::Json(json!({
+            "name": "Notes", "x": 0, "y": 0, "w": 12, "h": 2,
+        })))
+        .with_status(201)
+        .with_body(r#"{"id": "077b3d4e5f603000", "x": 0, "y": 0, "w": 12, "h": 2}"#)
+        .expect(1)
+        .create();
+    let view_body = json!({
+        "name": "Notes",
+        "properties": {"type": "markdown", "shape": "chronograf-v2", "note": "# Servers"},
+    });
+    let set_view = mockito::mock(
+        "PATCH",
+        "/api/v2/dashboards/077b3d4e5f601000/cells/077b3d4e5f603000/view",
+    )
+    .match_body(mockito::Matcher::Json(view_body.clone()))
+    .with_status(200)
+    .with_body(view_body.to_string())
+    .expect(1)
+    .create();
+    let get_view = mockito::mock(
+        "GET",
+        "/api/v2/dashboards/077b3d4e5f601000/cells/077b3d4e5f603000/view",
+    )
+    .with_status(200)
+    .with_body(
+        json!({
+            "id": "077b3d4e5f603000",
+            "name": "Notes",
+            "properties": {"type": "markdown", "shape": "chronograf-v2", "note": "# Servers"},
+        })
+        .to_string(),
+    )
+    .expect(1)
+    .create();
+    let move_cell = mockito::mock(
+        "PATCH",
+        "/api/v2/dashboards/077b3d4e5f601000/cells/077b3d4e5f603000",
+    )
+    .match_body(mockito::Matcher::Json(
+        json!({"x": 0, "y": 4, "w": 6, "h": 2}),
+    ))
+    .with_status(200)
+    .with_body(r#"{"id": "077b3d4e5f603000", "x": 0, "y": 4, "w": 6, "h": 2}"#)
+    .expect(1)
+    .create();
+    let delete = mockito::mock(
+        "DELETE",
+        "/api/v2/dashboards/077b3d4e5f601000/cells/077b3d4e5f603000",
+    )
+    .with_status(204)
+    .expect(1)
+    .create();
+
+    let client = client();
+    let dashboards = client.dashboards();
+    tokio_test::block_on(async {
+        let view = View::markdown("Notes", "# Servers");
+        let cell = dashboards
+            .add_cell("077b3d4e5f601000", CellPosition::new(0, 0, 12, 2), &view)
+            .await
+            .unwrap();
+
+        let shown = dashboards.view("077b3d4e5f601000", &cell.id).await.unwrap();
+        assert_eq!(shown.id.as_deref(), Some("077b3d4e5f603000"));
+        assert_eq!(shown.properties, view.properties);
+
+        let moved = dashboards
+            .update_cell("077b3d4e5f601000", &cell.id, CellPosition::new(0, 4, 6, 2))
+            .await
+            .unwrap();
+        assert_eq!(moved.y, 4);
+        dashboards
+            .delete_cell("077b3d4e5f601000", &cell.id)
+            .await
+            .unwrap();
+    });
+
+    add.assert();
+    set_view.assert();
+    get_view.assert();
+    move_cell.assert();
+    delete.assert();
+}
+
+#[test]
+fn test_dashboards_add_cell_view_fails() {
+    let add = mockito::mock("POST", "/api/v2/dashboards/077b3d4e5f605000/cells")
+        .with_status(201)
+        .with_body(r#"{"id": "077b3d4e5f606000", "x": 0, "y": 0, "w": 12, "h": 2}"#)
+        .expect(1)
+        .create();
+    let set_view = mockito::mock(
+        "PATCH",
+        "/api/v2/dashboards/077b3d4e5f605000/cells/077b3d4e5f606000/view",
+    )
+    .with_status(400)
+    .with_body(r#"{"code": "invalid", "message": "invalid view properties"}"#)
+    .expect(1)
+    .create();
+    let delete = mockito::mock(
+        "DELETE",
+        "/api/v2/dashboards/077b3d4e5f605000/cells/077b3d4e5f606000",
+    )
+    .with_status(204)
+    .expect(1)
+    .create();
+
+    let result = tokio_test::block_on(client().dashboards().add_cell(
+        "077b3d4e5f605000",
+        CellPosition::new(0, 0, 12, 2),
+        &View::markdown("Notes", "# Servers"),
+    ));
+
+    assert!(matches!(result, Err(InfluxError::InvalidSyntax(_))));
+
+    add.assert();
+    set_view.assert();
+    delete.assert();
+}
+
+#[test]
+fn test_labels_and_dashboards_list_org_lookup_fails() {
+    let orgs = mockito::mock("GET", "/api/v2/orgs")
+        .match_query(mockito::Matcher::UrlEncoded("org".into(), "tradely".into()))
+        .with_status(401)
+        .with_body(r#"{"code": "unauthorized", "message": "unauthorized access"}"#)
+        .expect(2)
+        .create();
+
+    let client = Client::new(mockito::server_url(), "TEST_API_KEY")
+        .unwrap()
+        .with_org("tradely");
+    tokio_test::block_on(async {
+        assert!(matches!(
+            client.labels().list().await,
+            Err(InfluxError::InvalidCredentials(_))
+        ));
+        assert!(matches!(
+            client.dashboards().list(&ListOptions::default()).await,
+            Err(InfluxError::InvalidCredentials(_))
+        ));
+    });
+
+    orgs.assert();
+}
@@ -0,0 +1,96 @@
+use influxdb_client::api::{DbrpFilter, DbrpUpdate, NewDbrp};
+use influxdb_client::Client;
+
+use mockito::Matcher;
+use serde_json::json;
+
+const DBRP: &str = r#"{
+    "id": "077c1a2b3c4d5000",
+    "orgID": "168f31904923e853",
+    "bucketID": "0fa1c5d0a3e2b000",
+    "database": "telegraf",
+    "retention_policy": "autogen",
+    "default": true,
+    "virtual": false
+}"#;
+
+fn client() -> Client {
+    Client::new(mockito::server_url(), "TEST_API_KEY")
+        .unwrap()
+        .with_org_id("168f31904923e853")
+}
+
+#[test]
+fn test_dbrps_create() {
+    let mock = mockito::mock("POST", "/api/v2/dbrps")
+        .match_body(Matcher::Json(json!({
+            "orgID": "168f31904923e853",
+            "bucketID": "0fa1c5d0a3e2b000",
+            "database": "telegraf",
+            "retention_policy": "autogen",
+            "default": true,
+        })))
+        .with_status(201)
+        .with_body(DBRP)
+        .expect(1)
+        .create();
+
+    let new_dbrp = NewDbrp::new("0fa1c5d0a3e2b000", "telegraf", "autogen").with_default(true);
+    let dbrp = tokio_test::block_on(client().dbrps().create(&new_dbrp)).unwrap();
+
+    assert_eq!(dbrp.id, "077c1a2b3c4d5000");
+    assert!(dbrp.default);
+    assert!(!dbrp.is_virtual);
+
+    mock.assert();
+}
+
+#[test]
+fn test_dbrps_list_update_delete() {
+    let list = mockito::mock("GET", "/api/v2/dbrps")
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("orgID".into(), "168f31904923e853".into()),
+            Matcher::UrlEncoded("db".into(), "telegraf".into()),
+            Matcher::UrlEncoded("default".into(), "true".into()),
+        ]))
+        .with_status(200)
+        .with_body(format!(r#"{{"content": [{}]}}"#, DBRP))
+        .expect(1)
+        .create();
+    let update = mockito::mock("PATCH", "/api/v2/dbrps/077c1a2b3c4d5000")
+        .match_query(Matcher::UrlEncoded(
+            "orgID".into(),
+            "168f31904923e853".into(),
+        ))
+        .match_body(Matcher::Json(json!({"retention_policy": "weekly"})))
+        .with_status(200)
+        .with_body(format!(r#"{{"content": {}}}"#, DBRP))
+        .expect(1)
+        .create();
+    let delete = mockito::mock("DELETE", "/api/v2/dbrps/077c1a2b3c4d5000")
+        .match_query(Matcher::UrlEncoded(
+            "orgID".into(),
+            "168f31904923e853".into(),
+        ))
+        .with_status(204)
+        .expect(1)
+        .create();
+
+    let client = client();
+    let dbrps = client.dbrps();
+    tokio_test::block_on(async {
+        let filter = DbrpFilter::default()
+            .with_database("telegraf")
+            .with_default(true);
+        let listed = dbrps.list(&filter).await.unwrap();
+        assert_eq!(listed[0].bucket_id, "0fa1c5d0a3e2b000");
+
+        let update = DbrpUpdate::default().with_retention_policy("weekly");
+        dbrps.update(&listed[0].id, &update).await.unwrap();
+        dbrps.delete(&listed[0].id).await.unwrap();
+    });
+
+    list.assert();
+    update.assert();
+    delete.assert();
+}
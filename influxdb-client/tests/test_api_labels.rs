@@ -0,0 +1,124 @@
+use influxdb_client::api::{LabelUpdate, NewLabel};
+use influxdb_client::Client;
+
+use serde_json::json;
+
+const LABEL: &str = r##"{"label": {
+    "id": "077b1a2b3c4d5000",
+    "orgID": "168f31904923e853",
+    "name": "production",
+    "properties": {"color": "#326BBA", "description": "Live systems"}
+}}"##;
+
+fn client() -> Client {
+    Client::new(mockito::server_url(), "TEST_API_KEY")
+        .unwrap()
+        .with_org_id("168f31904923e853")
+}
+
+#[test]
+fn test_labels_crud() {
+    let create = mockito::mock("POST", "/api/v2/labels")
+        .match_body(mockito::Matcher::Json(json!({
+            "orgID": "168f31904923e853",
+            "name": "production",
+            "properties": {"color": "#326BBA", "description": "Live systems"},
+        })))
+        .with_status(201)
+        .with_body(LABEL)
+        .expect(1)
+        .create();
+    let list = mockito::mock("GET", "/api/v2/labels")
+        .match_query(mockito::Matcher::UrlEncoded(
+            "orgID".into(),
+            "168f31904923e853".into(),
+        ))
+        .with_status(200)
+        .with_body(r#"{"labels": [{"id": "077b1a2b3c4d5000", "name": "production"}]}"#)
+        .expect(1)
+        .create();
+    let update = mockito::mock("PATCH", "/api/v2/labels/077b1a2b3c4d5000")
+        .match_body(mockito::Matcher::Json(json!({
+            "name": "prod",
+            "properties": {"color": ""},
+        })))
+        .with_status(200)
+        .with_body(LABEL)
+        .expect(1)
+        .create();
+    let delete = mockito::mock("DELETE", "/api/v2/labels/077b1a2b3c4d5000")
+        .with_status(204)
+        .expect(1)
+        .create();
+
+    let client = client();
+    let labels = client.labels();
+    tokio_test::block_on(async {
+        let new_label = NewLabel::new("production")
+            .with_color("#326BBA")
+            .with_description("Live systems");
+        let label = labels.create(&new_label).await.unwrap();
+        assert_eq!(label.properties["color"], "#326BBA");
+
+        let listed = labels.list().await.unwrap();
+        assert_eq!(listed[0].org_id, None);
+        assert!(listed[0].properties.is_empty());
+
+        let update = LabelUpdate::default()
+            .with_name("prod")
+            .with_property("color", "");
+        labels.update(&label.id, &update).await.unwrap();
+        labels.delete(&label.id).await.unwrap();
+    });
+
+    create.assert();
+    list.assert();
+    update.assert();
+    delete.assert();
+}
+
+#[test]
+fn test_labels_attach_to_buckets_and_tasks() {
+    let add = mockito::mock("POST", "/api/v2/buckets/0fa1c5d0a3e2b000/labels")
+        .match_body(mockito::Matcher::Json(
+            json!({"labelID": "077b1a2b3c4d5000"}),
+        ))
+        .with_status(201)
+        .with_body(LABEL)
+        .expect(1)
+        .create();
+    let list = mockito::mock("GET", "/api/v2/tasks/0779d7e3e4a1f000/labels")
+        .with_status(200)
+        .with_body(r#"{"labels": [{"id": "077b1a2b3c4d5000", "name": "production"}]}"#)
+        .expect(1)
+        .create();
+    let remove = mockito::mock(
+        "DELETE",
+        "/api/v2/tasks/0779d7e3e4a1f000/labels/077b1a2b3c4d5000",
+    )
+    .with_status(204)
+    .expect(1)
+    .create();
+
+    let client = client();
+    tokio_test::block_on(async {
+        let label = client
+            .buckets()
+            .add_label("0fa1c5d0a3e2b000", "077b1a2b3c4d5000")
+            .await
+            .unwrap();
+        assert_eq!(label.name, "production");
+
+        let labels = client.tasks().labels("0779d7e3e4a1f000").await.unwrap();
+        assert_eq!(labels[0].id, "077b1a2b3c4d5000");
+        client
+            .tasks()
+            .remove_label("0779d7e3e4a1f000", &labels[0].id)
+            .await
+            .unwrap();
+    });
+
+    add.assert();
+    list.assert();
+    remove.assert();
+}
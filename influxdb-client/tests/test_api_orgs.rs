@@ -0,0 +1,221 @@
+use influxdb_client::api::{ListOptions, NewBucket, OrganizationUpdate, Role, Status, UserUpdate};
+use influxdb_client::{Client, InfluxError};
+
+use serde_json::json;
+
+const ORG: &str =
+    r#"{"id": "168f31904923e853", "name": "tradely", "description": "", "links": {}}"#;
+
+fn client() -> Client {
+    Client::new(mockito::server_url(), "TEST_API_KEY").unwrap()
+}
+
+#[test]
+fn test_orgs_crud() {
+    let create = mockito::mock("POST", "/api/v2/orgs")
+        .match_body(mockito::Matcher::Json(json!({
+            "name": "tradely",
+            "description": "Trading",
+        })))
+        .with_status(201)
+        .with_body(ORG)
+        .expect(1)
+        .create();
+    let list = mockito::mock("GET", "/api/v2/orgs")
+        .match_query(mockito::Matcher::UrlEncoded("limit".into(), "5".into()))
+        .with_status(200)
+        .with_body(format!(r#"{{"orgs": [{}]}}"#, ORG))
+        .expect(1)
+        .create();
+    let update = mockito::mock("PATCH", "/api/v2/orgs/168f31904923e853")
+        .match_body(mockito::Matcher::Json(json!({"name": "tradely-2"})))
+        .with_status(200)
+        .with_body(ORG)
+        .expect(1)
+        .create();
+    let delete = mockito::mock("DELETE", "/api/v2/orgs/168f31904923e853")
+        .with_status(204)
+        .expect(1)
+        .create();
+
+    let client = client();
+    let orgs = client.orgs();
+    tokio_test::block_on(async {
+        let org = orgs.create("tradely", Some("Trading")).await.unwrap();
+        assert_eq!(org.id, "168f31904923e853");
+
+        let listed = orgs
+            .list(&ListOptions::default().with_limit(5))
+            .await
+            .unwrap();
+        assert_eq!(listed, vec![org.clone()]);
+
+        orgs.update(
+            &org.id,
+            &OrganizationUpdate::default().with_name("tradely-2"),
+        )
+        .await
+        .unwrap();
+        orgs.delete(&org.id).await.unwrap();
+    });
+
+    create.assert();
+    list.assert();
+    update.assert();
+    delete.assert();
+}
+
+#[test]
+fn test_orgs_create_without_description() {
+    let create = mockito::mock("POST", "/api/v2/orgs")
+        .match_body(mockito::Matcher::Json(json!({"name": "undescribed"})))
+        .with_status(201)
+        .with_body(ORG)
+        .expect(1)
+        .create();
+
+    tokio_test::block_on(async {
+        client().orgs().create("undescribed", None).await.unwrap();
+    });
+
+    create.assert();
+}
+
+#[test]
+fn test_orgs_members_and_owners() {
+    let members = mockito::mock("GET", "/api/v2/orgs/168f31904923e853/members")
+        .with_status(200)
+        .with_body(
+            r#"{"users": [{"id": "0a1", "name": "anders", "status": "active", "role": "member"}]}"#,
+        )
+        .expect(1)
+        .create();
+    let add_owner = mockito::mock("POST", "/api/v2/orgs/168f31904923e853/owners")
+        .match_body(mockito::Matcher::Json(json!({"id": "0a1"})))
+        .with_status(201)
+        .with_body(r#"{"id": "0a1", "name": "anders", "role": "owner"}"#)
+        .expect(1)
+        .create();
+    let remove_member = mockito::mock("DELETE", "/api/v2/orgs/168f31904923e853/members/0a1")
+        .with_status(204)
+        .expect(1)
+        .create();
+
+    let client = client();
+    let orgs = client.orgs();
+    tokio_test::block_on(async {
+        let members = orgs.members("168f31904923e853").await.unwrap();
+        assert_eq!(members[0].role, Role::Member);
+        assert_eq!(members[0].status, Some(Status::Active));
+
+        let owner = orgs.add_owner("168f31904923e853", "0a1").await.unwrap();
+        assert_eq!(owner.role, Role::Owner);
+
+        orgs.remove_member("168f31904923e853", "0a1").await.unwrap();
+    });
+
+    members.assert();
+    add_owner.assert();
+    remove_member.assert();
+}
+
+#[test]
+fn test_users() {
+    let create = mockito::mock("POST", "/api/v2/users")
+        .match_body(mockito::Matcher::Json(json!({"name": "anders"})))
+        .with_status(201)
+        .with_body(r#"{"id": "0a1", "name": "anders", "status": "active"}"#)
+        .expect(1)
+        .create();
+    let password = mockito::mock("POST", "/api/v2/users/0a1/password")
+        .match_body(mockito::Matcher::Json(json!({"password": "hunter22"})))
+        .with_status(204)
+        .expect(1)
+        .create();
+    let deactivate = mockito::mock("PATCH", "/api/v2/users/0a1")
+        .match_body(mockito::Matcher::Json(json!({"status": "inactive"})))
+        .with_status(200)
+        .with_body(r#"{"id": "0a1", "name": "anders", "status": "inactive"}"#)
+        .expect(1)
+        .create();
+    let me = mockito::mock("GET", "/api/v2/me")
+        .with_status(200)
+        .with_body(r#"{"id": "0a0", "name": "admin"}"#)
+        .expect(1)
+        .create();
+
+    let client = client();
+    let users = client.users();
+    tokio_test::block_on(async {
+        let user = users.create("anders").await.unwrap();
+        users.set_password(&user.id, "hunter22").await.unwrap();
+        let user = users
+            .update(
+                &user.id,
+                &UserUpdate::default().with_status(Status::Inactive),
+            )
+            .await
+            .unwrap();
+        assert_eq!(user.status, Some(Status::Inactive));
+        assert_eq!(users.me().await.unwrap().name, "admin");
+    });
+
+    create.assert();
+    password.assert();
+    deactivate.assert();
+    me.assert();
+}
+
+#[test]
+fn test_org_id_is_resolved_once() {
+    let lookup = mockito::mock("GET", "/api/v2/orgs")
+        .match_query(mockito::Matcher::UrlEncoded("org".into(), "tradely".into()))
+        .with_status(200)
+        .with_body(format!(r#"{{"orgs": [{}]}}"#, ORG))
+        .expect(1)
+        .create();
+    let create = mockito::mock("POST", "/api/v2/buckets")
+        .match_body(mockito::Matcher::PartialJson(
+            json!({"orgID": "168f31904923e853"}),
+        ))
+        .with_status(201)
+        .with_body(r#"{"id": "0b3", "orgID": "168f31904923e853", "name": "customer-42"}"#)
+        .expect(2)
+        .create();
+
+    let client = client().with_org("tradely");
+    let clone = client.clone();
+    tokio_test::block_on(async {
+        client
+            .buckets()
+            .create(&NewBucket::new("customer-42"))
+            .await
+            .unwrap();
+        clone
+            .buckets()
+            .create(&NewBucket::new("customer-42"))
+            .await
+            .unwrap();
+        assert_eq!(clone.resolve_org_id().await.unwrap(), "168f31904923e853");
+    });
+
+    lookup.assert();
+    create.assert();
+}
+
+#[test]
+fn test_org_id_unknown_org() {
+    let lookup = mockito::mock("GET", "/api/v2/orgs")
+        .match_query(mockito::Matcher::UrlEncoded("org".into(), "nobody".into()))
+        .with_status(404)
+        .with_body(r#"{"code": "not found", "message": "organization name \"nobody\" not found"}"#)
+        .expect(1)
+        .create();
+
+    let client = client().with_org("nobody");
+    let result = tokio_test::block_on(client.resolve_org_id());
+
+    assert!(matches!(result, Err(InfluxError::MissingOrg)));
+
+    lookup.assert();
+}
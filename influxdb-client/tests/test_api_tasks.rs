@@ -0,0 +1,194 @@
+use influxdb_client::api::{ListOptions, NewTask, RunStatus, Schedule, Status, TaskUpdate};
+use influxdb_client::{Client, InfluxError};
+
+use serde_json::json;
+
+const TASK: &str = r#"{
+    "id": "0779d7e3e4a1f000",
+    "orgID": "168f31904923e853",
+    "org": "tradely",
+    "name": "downsample",
+    "status": "active",
+    "flux": "option task = {name: \"downsample\", every: 1h, offset: 5m}\n\nfrom(bucket: \"raw\")",
+    "every": "1h",
+    "offset": "5m",
+    "latestCompleted": "2021-03-05T14:00:00Z",
+    "lastRunStatus": "success",
+    "links": {}
+}"#;
+
+fn client() -> Client {
+    Client::new(mockito::server_url(), "TEST_API_KEY")
+        .unwrap()
+        .with_org_id("168f31904923e853")
+}
+
+#[test]
+fn test_tasks_create() {
+    let mock = mockito::mock("POST", "/api/v2/tasks")
+        .match_body(mockito::Matcher::Json(json!({
+            "orgID": "168f31904923e853",
+            "flux": "option task = {name: \"downsample\", every: 1h, offset: 5m}\n\nfrom(bucket: \"raw\")",
+            "description": "Downsample raw data",
+        })))
+        .with_status(201)
+        .with_body(TASK)
+        .expect(1)
+        .create();
+
+    let new_task = NewTask::new(
+        "downsample",
+        Schedule::Every("1h".to_string()),
+        r#"from(bucket: "raw")"#,
+    )
+    .with_offset("5m")
+    .with_description("Downsample raw data");
+    let task = tokio_test::block_on(client().tasks().create(&new_task)).unwrap();
+
+    assert_eq!(task.every.as_deref(), Some("1h"));
+    assert_eq!(task.cron, None);
+    assert_eq!(task.status, Status::Active);
+    assert_eq!(task.last_run_status.as_deref(), Some("success"));
+
+    mock.assert();
+}
+
+#[test]
+fn test_tasks_create_cron() {
+    let mock = mockito::mock("POST", "/api/v2/tasks")
+        .match_body(mockito::Matcher::PartialJson(json!({
+            "flux": "option task = {name: \"say \\\"hi\\\"\", cron: \"0 * * * *\"}\n\nfrom(bucket: \"raw\")",
+            "status": "inactive",
+        })))
+        .with_status(201)
+        .with_body(TASK)
+        .expect(1)
+        .create();
+
+    let new_task = NewTask::new(
+        r#"say "hi""#,
+        Schedule::Cron("0 * * * *".to_string()),
+        r#"from(bucket: "raw")"#,
+    )
+    .with_status(Status::Inactive);
+    tokio_test::block_on(client().tasks().create(&new_task)).unwrap();
+
+    mock.assert();
+}
+
+#[test]
+fn test_tasks_list_update_delete() {
+    let list = mockito::mock("GET", "/api/v2/tasks")
+        .match_query(mockito::Matcher::AllOf(vec![
+            mockito::Matcher::UrlEncoded("name".into(), "downsample".into()),
+            mockito::Matcher::UrlEncoded("orgID".into(), "168f31904923e853".into()),
+        ]))
+        .with_status(200)
+        .with_body(format!(r#"{{"tasks": [{}]}}"#, TASK))
+        .expect(1)
+        .create();
+    let update = mockito::mock("PATCH", "/api/v2/tasks/0779d7e3e4a1f000")
+        .match_body(mockito::Matcher::Json(json!({
+            "status": "inactive",
+            "every": "2h",
+        })))
+        .with_status(200)
+        .with_body(TASK)
+        .expect(1)
+        .create();
+    let delete = mockito::mock("DELETE", "/api/v2/tasks/0779d7e3e4a1f000")
+        .with_status(204)
+        .expect(1)
+        .create();
+
+    let client = client();
+    let tasks = client.tasks();
+    tokio_test::block_on(async {
+        let listed = tasks
+            .list(&ListOptions::default().with_name("downsample"))
+            .await
+            .unwrap();
+        let update = TaskUpdate::default()
+            .with_status(Status::Inactive)
+            .with_schedule(Schedule::Every("2h".to_string()));
+        tasks.update(&listed[0].id, &update).await.unwrap();
+        tasks.delete(&listed[0].id).await.unwrap();
+    });
+
+    list.assert();
+    update.assert();
+    delete.assert();
+}
+
+#[test]
+fn test_task_update_replaces_schedule() {
+    let update = TaskUpdate::default()
+        .with_schedule(Schedule::Cron("0 * * * *".to_string()))
+        .with_schedule(Schedule::Every("2h".to_string()));
+    assert_eq!(
+        serde_json::to_value(&update).unwrap(),
+        json!({"every": "2h"})
+    );
+
+    let update = update.with_schedule(Schedule::Cron("0 * * * *".to_string()));
+    assert_eq!(
+        serde_json::to_value(&update).unwrap(),
+        json!({"cron": "0 * * * *"})
+    );
+}
+
+#[test]
+fn test_tasks_list_org_lookup_fails() {
+    let orgs = mockito::mock("GET", "/api/v2/orgs")
+        .match_query(mockito::Matcher::UrlEncoded("org".into(), "tradely".into()))
+        .with_status(401)
+        .with_body(r#"{"code": "unauthorized", "message": "unauthorized access"}"#)
+        .expect(1)
+        .create();
+
+    let client = Client::new(mockito::server_url(), "TEST_API_KEY")
+        .unwrap()
+        .with_org("tradely");
+    let result = tokio_test::block_on(client.tasks().list(&ListOptions::default()));
+
+    assert!(matches!(result, Err(InfluxError::InvalidCredentials(_))));
+
+    orgs.assert();
+}
+
+#[test]
+fn test_tasks_runs_and_logs() {
+    let run = mockito::mock("POST", "/api/v2/tasks/0779d7e3e4a1f000/runs")
+        .with_status(201)
+        .with_body(r#"{"id": "077a1", "taskID": "0779d7e3e4a1f000", "status": "scheduled", "scheduledFor": "2021-03-05T15:00:00Z"}"#)
+        .expect(1)
+        .create();
+    let runs = mockito::mock("GET", "/api/v2/tasks/0779d7e3e4a1f000/runs")
+        .with_status(200)
+        .with_body(r#"{"runs": [{"id": "077a1", "taskID": "0779d7e3e4a1f000", "status": "failed", "log": [{"runID": "077a1", "time": "2021-03-05T15:00:01Z", "message": "bucket not found"}]}]}"#)
+        .expect(1)
+        .create();
+    let logs = mockito::mock("GET", "/api/v2/tasks/0779d7e3e4a1f000/runs/077a1/logs")
+        .with_status(200)
+        .with_body(r#"{"events": [{"runID": "077a1", "time": "2021-03-05T15:00:01Z", "message": "bucket not found"}]}"#)
+        .expect(1)
+        .create();
+
+    let client = client();
+    let tasks = client.tasks();
+    tokio_test::block_on(async {
+        let started = tasks.run("0779d7e3e4a1f000").await.unwrap();
+        assert_eq!(started.status, RunStatus::Scheduled);
+
+        let runs = tasks.runs("0779d7e3e4a1f000").await.unwrap();
+        assert_eq!(runs[0].status, RunStatus::Failed);
+        assert_eq!(runs[0].log[0].message, "bucket not found");
+
+        let events = tasks.run_logs("0779d7e3e4a1f000", "077a1").await.unwrap();
+        assert_eq!(events[0].run_id.as_deref(), Some("077a1"));
+    });
+
+    run.assert();
+    runs.assert();
+    logs.assert();
+}
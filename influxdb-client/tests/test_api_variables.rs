@@ -0,0 +1,127 @@
+use std::collections::HashMap;
+
+use influxdb_client::api::{Variable, VariableArguments};
+use influxdb_client::Client;
+
+use serde_json::json;
+
+fn client() -> Client {
+    Client::new(mockito::server_url(), "TEST_API_KEY")
+        .unwrap()
+        .with_org_id("168f31904923e853")
+}
+
+#[test]
+fn test_variables_create_query() {
+    let mock = mockito::mock("POST", "/api/v2/variables")
+        .match_body(mockito::Matcher::Json(json!({
+            "orgID": "168f31904923e853",
+            "name": "host",
+            "selected": ["db1"],
+            "arguments": {
+                "type": "query",
+                "values": {"query": "buckets()", "language": "flux"},
+            },
+        })))
+        .with_status(201)
+        .with_body(
+            r#"{
+            "id": "077b2c3d4e5f6000",
+            "orgID": "168f31904923e853",
+            "name": "host",
+            "selected": ["db1"],
+            "arguments": {"type": "query", "values": {"query": "buckets()", "language": "flux"}},
+            "labels": [],
+            "links": {}
+        }"#,
+        )
+        .expect(1)
+        .create();
+
+    let variable =
+        Variable::new("host", VariableArguments::query("buckets()")).with_selected("db1");
+    let created = tokio_test::block_on(client().variables().create(&variable)).unwrap();
+
+    assert_eq!(created.id.as_deref(), Some("077b2c3d4e5f6000"));
+    assert_eq!(created.arguments, variable.arguments);
+
+    mock.assert();
+}
+
+#[test]
+fn test_variables_update_new() {
+    let mock = mockito::mock("PUT", "/api/v2/variables/077b2c3d4e5f9000")
+        .match_body(mockito::Matcher::Json(json!({
+            "orgID": "168f31904923e853",
+            "name": "zone",
+            "arguments": {"type": "constant", "values": ["a", "b"]},
+        })))
+        .with_status(200)
+        .with_body(r#"{"id": "077b2c3d4e5f9000", "orgID": "168f31904923e853", "name": "zone", "arguments": {"type": "constant", "values": ["a", "b"]}}"#)
+        .expect(1)
+        .create();
+
+    let variable = Variable::new(
+        "zone",
+        VariableArguments::Constant(vec!["a".to_string(), "b".to_string()]),
+    );
+    tokio_test::block_on(client().variables().update("077b2c3d4e5f9000", &variable)).unwrap();
+
+    mock.assert();
+}
+
+#[test]
+fn test_variables_list_update_delete() {
+    let list = mockito::mock("GET", "/api/v2/variables")
+        .match_query(mockito::Matcher::UrlEncoded(
+            "orgID".into(),
+            "168f31904923e853".into(),
+        ))
+        .with_status(200)
+        .with_body(
+            r#"{"variables": [
+            {"id": "077b2c3d4e5f7000", "orgID": "168f31904923e853", "name": "region",
+             "arguments": {"type": "constant", "values": ["eu", "us"]}},
+            {"id": "077b2c3d4e5f8000", "orgID": "168f31904923e853", "name": "env",
+             "arguments": {"type": "map", "values": {"Production": "prod"}}}
+        ]}"#,
+        )
+        .expect(1)
+        .create();
+    let update = mockito::mock("PUT", "/api/v2/variables/077b2c3d4e5f7000")
+        .match_body(mockito::Matcher::PartialJson(json!({
+            "arguments": {"type": "constant", "values": ["eu", "us", "ap"]},
+        })))
+        .with_status(200)
+        .with_body(r#"{"id": "077b2c3d4e5f7000", "name": "region", "arguments": {"type": "constant", "values": ["eu", "us", "ap"]}}"#)
+        .expect(1)
+        .create();
+    let delete = mockito::mock("DELETE", "/api/v2/variables/077b2c3d4e5f8000")
+        .with_status(204)
+        .expect(1)
+        .create();
+
+    let client = client();
+    let variables = client.variables();
+    tokio_test::block_on(async {
+        let listed = variables.list().await.unwrap();
+        assert_eq!(
+            listed[1].arguments,
+            VariableArguments::Map(HashMap::from([(
+                "Production".to_string(),
+                "prod".to_string()
+            )]))
+        );
+
+        let mut region = listed[0].clone();
+        if let VariableArguments::Constant(values) = &mut region.arguments {
+            values.push("ap".to_string());
+        }
+        variables.update("077b2c3d4e5f7000", &region).await.unwrap();
+        variables.delete("077b2c3d4e5f8000").await.unwrap();
+    });
+
+    list.assert();
+    update.assert();
+    delete.assert();
+}
@@ -0,0 +1,231 @@
+use std::time::Duration;
+
+use influxdb_client::{BatchOptions, BatchWriter, Client, InfluxError, Point};
+
+fn client() -> Client {
+    Client::new(mockito::server_url(), "TEST_API_KEY")
+        .unwrap()
+        .with_bucket("tradely")
+}
+
+fn point(price: f64) -> Point {
+    Point::new("test")
+        .tag("ticker", "GME")
+        .field("price", price)
+        .timestamp(price as i64)
+}
+
+#[tokio::test]
+async fn test_batch_writer_flush_on_lines() {
+    let first = mockito::mock("POST", "/api/v2/write")
+        .match_query(mockito::Matcher::Any)
+        .match_body("test,ticker=GME price=1 1\ntest,ticker=GME price=2 2")
+        .with_status(204)
+        .expect(1)
+        .create();
+    let second = mockito::mock("POST", "/api/v2/write")
+        .match_query(mockito::Matcher::Any)
+        .match_body("test,ticker=GME price=3 3")
+        .with_status(204)
+        .expect(1)
+        .create();
+
+    let writer = BatchWriter::new(
+        client(),
+        BatchOptions::default()
+            .with_max_lines(2)
+            .with_flush_interval(Duration::from_secs(60)),
+    );
+
+    for price in 1..=3 {
+        writer.write(&point(price as f64)).await.unwrap();
+    }
+    writer.flush().await.unwrap();
+
+    first.assert();
+    second.assert();
+}
+
+#[tokio::test]
+async fn test_batch_writer_flush_on_bytes() {
+    let mock = mockito::mock("POST", "/api/v2/write")
+        .match_query(mockito::Matcher::Any)
+        .match_body("test,ticker=GME price=1 1\ntest,ticker=GME price=2 2")
+        .with_status(204)
+        .expect(1)
+        .create();
+
+    let writer = BatchWriter::new(
+        client(),
+        BatchOptions::default()
+            .with_max_bytes(30)
+            .with_flush_interval(Duration::from_secs(60)),
+    );
+    let handle = writer.clone();
+
+    writer.write(&point(1.0)).await.unwrap();
+    handle.write(&point(2.0)).await.unwrap();
+    // Round-trip through the background task so the size-triggered flush has happened
+    writer.flush().await.unwrap();
+
+    mock.assert();
+}
+
+#[tokio::test]
+async fn test_batch_writer_flush_on_interval() {
+    let mock = mockito::mock("POST", "/api/v2/write")
+        .match_query(mockito::Matcher::Any)
+        .match_body("test,ticker=GME price=1 1")
+        .with_status(204)
+        .expect(1)
+        .create();
+
+    let writer = BatchWriter::new(
+        client(),
+        BatchOptions::default().with_flush_interval(Duration::from_millis(50)),
+    );
+
+    writer.write(&point(1.0)).await.unwrap();
+    tokio::time::sleep(Duration::from_millis(300)).await;
+
+    mock.assert();
+}
+
+#[tokio::test]
+async fn test_batch_writer_close() {
+    let mock = mockito::mock("POST", "/api/v2/write")
+        .match_query(mockito::Matcher::Any)
+        .with_status(400)
+        .with_body("unable to parse")
+        .expect(1)
+        .create();
+
+    let writer = BatchWriter::new(client(), BatchOptions::default());
+    let handle = writer.clone();
+
+    writer.write(&point(1.0)).await.unwrap();
+    let result = writer.close().await;
+    assert!(matches!(result, Err(InfluxError::InvalidSyntax(_))));
+
+    let result = handle.write(&point(2.0)).await;
+    assert!(matches!(result, Err(InfluxError::WriterClosed)));
+
+    mock.assert();
+}
+
+#[tokio::test]
+async fn test_batch_writer_zero_flush_interval() {
+    let mock = mockito::mock("POST", "/api/v2/write")
+        .match_query(mockito::Matcher::Any)
+        .match_body("test,ticker=GME price=1 1")
+        .with_status(204)
+        .expect(1)
+        .create();
+
+    let writer = BatchWriter::new(
+        client(),
+        BatchOptions::default().with_flush_interval(Duration::ZERO),
+    );
+
+    writer.write(&point(1.0)).await.unwrap();
+    writer.close().await.unwrap();
+
+    mock.assert();
+}
+
+#[tokio::test]
+async fn test_batch_writer_requeues_retryable_failure() {
+    let unavailable = mockito::mock("POST", "/api/v2/write")
+        .match_query(mockito::Matcher::Any)
+        .match_body("test,ticker=GME price=1 1")
+        .with_status(503)
+        .expect(1)
+        .create();
+    let retried = mockito::mock("POST", "/api/v2/write")
+        .match_query(mockito::Matcher::Any)
+        .match_body("test,ticker=GME price=1 1\ntest,ticker=GME price=2 2")
+        .with_status(204)
+        .expect(1)
+        .create();
+
+    let writer = BatchWriter::new(
+        client(),
+        BatchOptions::default().with_flush_interval(Duration::from_secs(60)),
+    );
+
+    writer.write(&point(1.0)).await.unwrap();
+    let result = writer.flush().await;
+    assert!(matches!(result, Err(InfluxError::ServiceUnavailable(_))));
+
+    writer.write(&point(2.0)).await.unwrap();
+    writer.flush().await.unwrap();
+
+    unavailable.assert();
+    retried.assert();
+}
+
+#[tokio::test]
+async fn test_batch_writer_drops_oldest_lines_while_failing() {
+    let unavailable = mockito::mock("POST", "/api/v2/write")
+        .match_query(mockito::Matcher::Any)
+        .match_body("test,ticker=GME price=11 11")
+        .with_status(503)
+        .expect(1)
+        .create();
+    let retried = mockito::mock("POST", "/api/v2/write")
+        .match_query(mockito::Matcher::Any)
+        .match_body("test,ticker=GME price=12 12\ntest,ticker=GME price=13 13")
+        .with_status(204)
+        .expect(1)
+        .create();
+
+    let writer = BatchWriter::new(
+        client(),
+        BatchOptions::default()
+            .with_max_lines(2)
+            .with_flush_interval(Duration::from_secs(60)),
+    );
+
+    writer.write(&point(11.0)).await.unwrap();
+    let result = writer.flush().await;
+    assert!(matches!(result, Err(InfluxError::ServiceUnavailable(_))));
+
+    // The failed batch waits for the next flush instead of being retried on every write
+    writer.write(&point(12.0)).await.unwrap();
+    writer.write(&point(13.0)).await.unwrap();
+    let result = writer.flush().await;
+    assert!(matches!(result, Err(InfluxError::LinesDropped(1))));
+
+    unavailable.assert();
+    retried.assert();
+}
+
+#[tokio::test]
+async fn test_batch_writer_reports_every_failure() {
+    let mock = mockito::mock("POST", "/api/v2/write")
+        .match_query(mockito::Matcher::Any)
+        .with_status(400)
+        .with_body("unable to parse")
+        .expect(2)
+        .create();
+
+    let writer = BatchWriter::new(
+        client(),
+        BatchOptions::default()
+            .with_max_lines(1)
+            .with_flush_interval(Duration::from_secs(60)),
+    );
+
+    writer.write(&point(1.0)).await.unwrap();
+    writer.write(&point(2.0)).await.unwrap();
+    match writer.flush().await {
+        Err(InfluxError::WritesFailed { count, first, last }) => {
+            assert_eq!(count, 2);
+            assert!(matches!(*first, InfluxError::InvalidSyntax(_)));
+            assert!(matches!(*last, InfluxError::InvalidSyntax(_)));
+        }
+        other => panic!("unexpected result {:?}", other),
+    }
+
+    mock.assert();
+}
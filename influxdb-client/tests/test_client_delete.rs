@@ -0,0 +1,114 @@
+use std::time::{Duration, UNIX_EPOCH};
+
+use influxdb_client::{Client, InfluxError, Precision, Predicate, Timestamp};
+
+use serde_json::json;
+
+#[test]
+fn test_predicate() {
+    assert_eq!(
+        Predicate::measurement("orders")
+            .and_tag("customer", "42")
+            .to_string(),
+        r#"_measurement="orders" AND customer="42""#
+    );
+    assert_eq!(
+        Predicate::tag("name", r#"Anders "the \ man""#).to_string(),
+        r#"name="Anders \"the \\ man\"""#
+    );
+    assert_eq!(
+        Predicate::tag("host name", "a")
+            .and_tag(r#"x" OR _measurement="cpu"#, "b")
+            .and_tag("1st", "c")
+            .and_tag("and", "d")
+            .to_string(),
+        r#""host name"="a" AND "x\" OR _measurement=\"cpu"="b" AND "1st"="c" AND "and"="d""#
+    );
+}
+
+#[test]
+fn test_timestamp_to_rfc3339() {
+    let cases = vec![
+        (
+            Timestamp::Int(0),
+            Precision::NS,
+            Some("1970-01-01T00:00:00Z"),
+        ),
+        (
+            Timestamp::Int(1614956250),
+            Precision::S,
+            Some("2021-03-05T14:57:30Z"),
+        ),
+        (
+            Timestamp::Precise(1614956250123456789, Precision::NS),
+            Precision::S,
+            Some("2021-03-05T14:57:30.123456789Z"),
+        ),
+        (
+            Timestamp::Int(-1),
+            Precision::MS,
+            Some("1969-12-31T23:59:59.999Z"),
+        ),
+        (
+            Timestamp::from("2000-02-29T12:00:00+01:00"),
+            Precision::NS,
+            Some("2000-02-29T11:00:00Z"),
+        ),
+        (Timestamp::from("now"), Precision::NS, None),
+    ];
+
+    for (timestamp, precision, expected) in cases {
+        assert_eq!(
+            timestamp.to_rfc3339(precision).as_deref(),
+            expected,
+            "{:?}",
+            timestamp
+        );
+    }
+}
+
+#[test]
+fn test_client_delete() {
+    let mock = mockito::mock("POST", "/api/v2/delete")
+        .match_query(mockito::Matcher::AllOf(vec![
+            mockito::Matcher::UrlEncoded("bucket".into(), "orders".into()),
+            mockito::Matcher::UrlEncoded("org".into(), "tradely".into()),
+        ]))
+        .match_body(mockito::Matcher::Json(json!({
+            "start": "1970-01-01T00:00:00Z",
+            "stop": "2021-03-05T14:57:30.5Z",
+            "predicate": "_measurement=\"orders\" AND customer=\"42\"",
+        })))
+        .with_status(204)
+        .expect(1)
+        .create();
+
+    let client = Client::new(mockito::server_url(), "TEST_API_KEY")
+        .unwrap()
+        .with_org("tradely")
+        .with_bucket("orders")
+        .with_precision(Precision::MS);
+    let predicate = Predicate::measurement("orders").and_tag("customer", "42");
+    let result = tokio_test::block_on(client.delete(
+        0i64,
+        UNIX_EPOCH + Duration::from_millis(1614956250500),
+        Some(&predicate),
+    ));
+
+    assert!(result.is_ok());
+
+    mock.assert();
+}
+
+#[test]
+fn test_client_delete_invalid_time() {
+    let client = Client::new("http://localhost:8086", "TEST_API_KEY").unwrap();
+
+    let result = tokio_test::block_on(client.delete(Timestamp::from("yesterday"), 0i64, None));
+
+    assert!(matches!(result, Err(InfluxError::InvalidTimestamp(_))));
+
+    let result = tokio_test::block_on(client.delete(None::<Timestamp>, 0i64, None));
+
+    assert!(matches!(result, Err(InfluxError::MissingTimestamp)));
+}
@@ -0,0 +1,118 @@
+use std::time::Duration;
+
+use influxdb_client::{Client, InfluxError, Point, TimestampOptions};
+
+fn insert_with_status(status: usize, body: &str) -> Result<(), InfluxError> {
+    insert_with_retry_after(status, body, "7")
+}
+
+fn insert_with_retry_after(
+    status: usize,
+    body: &str,
+    retry_after: &str,
+) -> Result<(), InfluxError> {
+    let mock = mockito::mock("POST", "/api/v2/write")
+        .match_query(mockito::Matcher::Any)
+        .with_status(status)
+        .with_header("retry-after", retry_after)
+        .with_body(body)
+        .expect(1)
+        .create();
+
+    let client = Client::new(mockito::server_url(), "TEST_API_KEY")
+        .unwrap()
+        .with_bucket("tradely");
+    let points = vec![Point::new("test").field("price", 420.69)];
+    let result = tokio_test::block_on(client.insert_points(&points, TimestampOptions::None));
+
+    mock.assert();
+    result
+}
+
+#[test]
+fn test_client_error_json_body() {
+    let result = insert_with_status(
+        400,
+        r#"{"code":"invalid","message":"unable to parse 'test price=': missing field value","line":2}"#,
+    );
+
+    match result {
+        Err(InfluxError::InvalidSyntax(error)) => {
+            assert_eq!(error.status, 400);
+            assert_eq!(error.code.as_deref(), Some("invalid"));
+            assert_eq!(
+                error.message,
+                "unable to parse 'test price=': missing field value"
+            );
+            assert_eq!(error.line, Some(2));
+        }
+        other => panic!("unexpected result: {:?}", other),
+    }
+}
+
+#[test]
+fn test_client_error_plain_body() {
+    let result = insert_with_status(502, "<html>Bad Gateway</html>");
+
+    match result {
+        Err(InfluxError::BadGateway(error)) => {
+            assert_eq!(error.code, None);
+            assert_eq!(error.message, "<html>Bad Gateway</html>");
+        }
+        other => panic!("unexpected result: {:?}", other),
+    }
+}
+
+#[test]
+fn test_client_error_statuses() {
+    let cases = vec![
+        (401, "InvalidCredentials"),
+        (403, "Forbidden"),
+        (404, "NotFound"),
+        (409, "Conflict"),
+        (413, "PayloadTooLarge"),
+        (422, "UnprocessableEntity"),
+        (429, "RateLimited"),
+        (500, "ServerError"),
+        (501, "ServerError"),
+        (503, "ServiceUnavailable"),
+        (504, "GatewayTimeout"),
+        (418, "Unknown"),
+    ];
+
+    for (status, expected) in cases {
+        let error = insert_with_status(status, r#"{"code":"x","message":"y"}"#).unwrap_err();
+        let variant = format!("{:?}", error);
+        assert!(
+            variant.starts_with(&format!("{}(", expected)),
+            "status {} gave {}",
+            status,
+            variant
+        );
+        assert_eq!(error.api_error().unwrap().status, status as u16);
+    }
+}
+
+#[test]
+fn test_client_error_retry_after() {
+    let error = insert_with_status(429, "slow down").unwrap_err();
+
+    assert!(error.is_retryable());
+    assert_eq!(error.retry_after(), Some(Duration::from_secs(7)));
+
+    let error = insert_with_status(413, "too large").unwrap_err();
+
+    assert!(!error.is_retryable());
+}
+
+#[test]
+fn test_client_error_retry_after_http_date() {
+    let error = insert_with_retry_after(503, "busy", "Wed, 21 Oct 2015 07:28:00 GMT").unwrap_err();
+    assert_eq!(error.retry_after(), Some(Duration::ZERO));
+
+    let error = insert_with_retry_after(503, "busy", "Fri, 01 Jan 2100 00:00:00 GMT").unwrap_err();
+    assert!(error.retry_after().unwrap() > Duration::from_secs(365 * 86400));
+
+    let error = insert_with_retry_after(503, "busy", "soon").unwrap_err();
+    assert_eq!(error.retry_after(), None);
+}
@@ -0,0 +1,116 @@
+#![cfg(feature = "gzip")]
+
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::thread;
+
+use influxdb_client::{Client, Point, Record, TimestampOptions, Value};
+
+use flate2::{read::GzDecoder, write::GzEncoder, Compression};
+
+fn gzip(body: &str) -> Vec<u8> {
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(body.as_bytes()).unwrap();
+    encoder.finish().unwrap()
+}
+
+/// Serve a single request with 204 and return its headers and body.
+///
+/// mockito cannot decode bodies before matching them, so the request is captured directly.
+fn capture_request() -> (String, thread::JoinHandle<(String, Vec<u8>)>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+
+    let handle = thread::spawn(move || {
+        let (stream, _) = listener.accept().unwrap();
+        let mut reader = BufReader::new(stream);
+        let mut head = String::new();
+        loop {
+            let mut line = String::new();
+            reader.read_line(&mut line).unwrap();
+            if line == "\r\n" {
+                break;
+            }
+            head.push_str(&line.to_lowercase());
+        }
+        let length = head
+            .lines()
+            .find_map(|line| line.strip_prefix("content-length:"))
+            .map_or(0, |value| value.trim().parse().unwrap());
+        let mut body = vec![0; length];
+        reader.read_exact(&mut body).unwrap();
+        reader
+            .get_mut()
+            .write_all(b"HTTP/1.1 204 No Content\r\nContent-Length: 0\r\n\r\n")
+            .unwrap();
+        (head, body)
+    });
+    (url, handle)
+}
+
+#[test]
+fn test_client_write_gzip() {
+    let (url, request) = capture_request();
+
+    let client = Client::new(url, "TEST_API_KEY")
+        .unwrap()
+        .with_bucket("tradely")
+        .with_gzip(true);
+
+    let points = vec![
+        Point::new("test").field("price", 420.69),
+        Point::new("test").field("price", 1337),
+    ];
+    let result = tokio_test::block_on(client.insert_points(&points, TimestampOptions::None));
+
+    assert!(result.is_ok(), "{:?}", result);
+    let (head, body) = request.join().unwrap();
+    assert!(head.contains("content-encoding: gzip"), "{}", head);
+    let mut decoded = String::new();
+    GzDecoder::new(&body[..])
+        .read_to_string(&mut decoded)
+        .unwrap();
+    assert_eq!(decoded, "test price=420.69\ntest price=1337i");
+}
+
+#[test]
+fn test_client_write_without_gzip() {
+    let mock = mockito::mock("POST", "/api/v2/write")
+        .match_query(mockito::Matcher::Any)
+        .match_header("content-encoding", mockito::Matcher::Missing)
+        .match_body("test price=420.69")
+        .with_status(204)
+        .expect(1)
+        .create();
+
+    let client = Client::new(mockito::server_url(), "TEST_API_KEY")
+        .unwrap()
+        .with_bucket("tradely");
+
+    let points = vec![Point::new("test").field("price", 420.69)];
+    let result = tokio_test::block_on(client.insert_points(&points, TimestampOptions::None));
+
+    assert!(result.is_ok());
+
+    mock.assert();
+}
+
+#[test]
+fn test_client_query_gzip_response() {
+    let body = "#datatype,string,long,double\r\n#group,false,false,false\r\n#default,_result,,\r\n,result,table,_value\r\n,,0,420.69\r\n\r\n";
+    let mock = mockito::mock("POST", "/api/v2/query")
+        .with_status(200)
+        .with_header("content-encoding", "gzip")
+        .with_body(gzip(body))
+        .expect(1)
+        .create();
+
+    let client = Client::new(mockito::server_url(), "TEST_API_KEY").unwrap();
+
+    let records =
+        tokio_test::block_on(client.query::<Record>("from(bucket: \"tradely\")")).unwrap();
+
+    assert_eq!(records[0].value(), Some(&Value::Float(420.69)));
+
+    mock.assert();
+}
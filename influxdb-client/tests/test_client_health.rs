@@ -0,0 +1,122 @@
+use std::net::TcpListener;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use influxdb_client::{Client, HealthStatus, InfluxError};
+
+fn client() -> Client {
+    Client::new(mockito::server_url(), "TEST_API_KEY").unwrap()
+}
+
+#[test]
+fn test_client_health() {
+    let mock = mockito::mock("GET", "/health")
+        .with_status(200)
+        .with_body(r#"{"name": "influxdb", "message": "ready for queries and writes", "status": "pass", "checks": [], "version": "2.0.4", "commit": "4e7a59bb9a"}"#)
+        .expect(1)
+        .create();
+
+    let health = tokio_test::block_on(client().health()).unwrap();
+
+    assert_eq!(health.status, HealthStatus::Pass);
+    assert_eq!(health.version.as_deref(), Some("2.0.4"));
+    assert_eq!(health.commit.as_deref(), Some("4e7a59bb9a"));
+
+    mock.assert();
+}
+
+#[test]
+fn test_client_health_fail() {
+    let mock = mockito::mock("GET", "/health")
+        .with_status(503)
+        .with_body(r#"{"name": "influxdb", "message": "unhealthy", "status": "fail", "checks": [{"name": "bolt", "status": "fail"}]}"#)
+        .expect(1)
+        .create();
+
+    let health = tokio_test::block_on(client().health()).unwrap();
+
+    assert_eq!(health.status, HealthStatus::Fail);
+    assert_eq!(health.checks[0].name, "bolt");
+
+    mock.assert();
+}
+
+#[test]
+fn test_client_ping() {
+    let mock = mockito::mock("GET", "/ping")
+        .with_status(204)
+        .with_header("X-Influxdb-Version", "2.0.4")
+        .with_header("X-Influxdb-Build", "OSS")
+        .expect(1)
+        .create();
+
+    let ping = tokio_test::block_on(client().ping()).unwrap();
+
+    assert_eq!(ping.version.as_deref(), Some("2.0.4"));
+    assert_eq!(ping.build.as_deref(), Some("OSS"));
+
+    mock.assert();
+}
+
+#[test]
+fn test_client_wait_until_ready() {
+    let starting = mockito::mock("GET", "/ready")
+        .with_status(503)
+        .with_body(r#"{"code": "unavailable", "message": "starting"}"#)
+        .expect(2)
+        .create();
+    let ready = mockito::mock("GET", "/ready")
+        .with_status(200)
+        .with_body(r#"{"status": "ready", "started": "2021-03-05T14:57:30Z", "up": "1.5s"}"#)
+        .expect(1)
+        .create();
+
+    let result = tokio_test::block_on(client().wait_until_ready(Duration::from_secs(10))).unwrap();
+
+    assert_eq!(result.status, "ready");
+    assert_eq!(result.up.as_deref(), Some("1.5s"));
+
+    starting.assert();
+    ready.assert();
+}
+
+#[test]
+fn test_client_wait_until_ready_timeout() {
+    let mock = mockito::mock("GET", "/ready")
+        .with_status(503)
+        .with_body("starting")
+        .expect(3)
+        .create();
+
+    // Attempts at 0 and 100ms, then a last one right away instead of waiting another 200ms
+    let result = tokio_test::block_on(client().wait_until_ready(Duration::from_millis(250)));
+
+    match result {
+        Err(InfluxError::RetriesExhausted { attempts, source }) => {
+            assert_eq!(attempts, 3);
+            assert!(matches!(*source, InfluxError::ServiceUnavailable(_)))
+        }
+        other => panic!("unexpected result: {:?}", other),
+    }
+
+    mock.assert();
+}
+
+#[test]
+fn test_client_wait_until_ready_hanging_server() {
+    // Accepts connections but never answers, so every request runs into its timeout
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    thread::spawn(move || {
+        let streams: Vec<_> = listener.incoming().collect();
+        drop(streams);
+    });
+
+    let client = Client::new(url, "TEST_API_KEY").unwrap();
+    let start = Instant::now();
+    let result = tokio_test::block_on(client.wait_until_ready(Duration::from_millis(500)));
+
+    assert!(matches!(result, Err(InfluxError::RetriesExhausted { .. })));
+    // Without capping the request timeout at the deadline this takes the full 3 seconds
+    assert!(start.elapsed() < Duration::from_secs(2));
+}
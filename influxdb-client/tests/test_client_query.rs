@@ -0,0 +1,173 @@
+use influxdb_client::derives::FromRecord;
+use influxdb_client::{Cell, Client, CsvError, InfluxError, Precision, Record, Timestamp, Value};
+
+use futures::StreamExt;
+use mockito::Matcher;
+
+const RESPONSE: &str = "#datatype,string,long,dateTime:RFC3339,double,string,string,string\r
+#group,false,false,false,false,true,true,true\r
+#default,_result,,,,,,\r
+,result,table,_time,_value,_field,_measurement,ticker\r
+,,0,2021-03-05T15:00:00Z,420.69,price,test,GME\r
+,,0,2021-03-05T15:01:00Z,,price,test,GME\r
+\r
+#datatype,string,long,dateTime:RFC3339,long,string,string,string\r
+#group,false,false,false,false,true,true,true\r
+#default,_result,,,,,,\r
+,result,table,_time,_value,_field,_measurement,ticker\r
+,,1,2021-03-05T15:00:00Z,1337,volume,test,\"GME, Inc\"\r
+\r
+";
+
+#[test]
+fn test_client_query() {
+    let mock = mockito::mock("POST", "/api/v2/query")
+        .with_status(200)
+        .with_header("content-type", "text/csv; charset=utf-8")
+        .match_header("content-type", "application/json")
+        .match_header("accept", "application/csv")
+        .match_query(Matcher::UrlEncoded("org".into(), "tradely".into()))
+        .match_body(Matcher::PartialJsonString(
+            r#"{"query": "from(bucket: \"tradely\")", "type": "flux"}"#.into(),
+        ))
+        .with_body(RESPONSE)
+        .expect(1)
+        .create();
+
+    let client = Client::new(mockito::server_url(), "TEST_API_KEY")
+        .unwrap()
+        .with_org("tradely");
+
+    let records: Vec<Record> =
+        tokio_test::block_on(client.query("from(bucket: \"tradely\")")).unwrap();
+
+    assert_eq!(records.len(), 3);
+    assert_eq!(records[0].measurement(), Some("test"));
+    assert_eq!(records[0].field(), Some("price"));
+    assert_eq!(records[0].value(), Some(&Value::Float(420.69)));
+    assert_eq!(
+        records[0].get("result"),
+        Some(&Cell::Value(Value::Str("_result".into())))
+    );
+    assert_eq!(records[1].get("_value"), Some(&Cell::Null));
+    assert_eq!(records[2].value(), Some(&Value::Int(1337)));
+    assert_eq!(
+        records[2].value_of("ticker"),
+        Some(&Value::Str("GME, Inc".into()))
+    );
+
+    mock.assert();
+}
+
+#[test]
+fn test_client_query_typed() {
+    #[derive(FromRecord)]
+    #[point(measurement = "test")]
+    struct Price {
+        #[point(tag)]
+        ticker: String,
+        #[point(field = "price")]
+        price: Option<f64>,
+        #[point(timestamp)]
+        time: Timestamp,
+    }
+
+    let mock = mockito::mock("POST", "/api/v2/query")
+        .with_status(200)
+        .with_body(RESPONSE.split("\r\n\r\n").next().unwrap())
+        .expect(1)
+        .create();
+
+    let client = Client::new(mockito::server_url(), "TEST_API_KEY").unwrap();
+
+    let prices = tokio_test::block_on(client.query::<Price>("from(bucket: \"tradely\")")).unwrap();
+
+    assert_eq!(prices.len(), 2);
+    assert_eq!(prices[0].ticker, "GME");
+    assert_eq!(prices[0].price, Some(420.69));
+    assert_eq!(
+        prices[0].time,
+        Timestamp::Precise(1614956400000000000, Precision::NS)
+    );
+    assert_eq!(prices[1].price, None);
+
+    mock.assert();
+}
+
+#[test]
+fn test_client_query_flux_error() {
+    let mock = mockito::mock("POST", "/api/v2/query")
+        .with_status(200)
+        .with_body(
+            "#datatype,string,string\r\n#group,true,true\r\n#default,,\r\n,error,reference\r\n,\"failed to execute query: bucket not found\",897\r\n\r\n",
+        )
+        .create();
+
+    let client = Client::new(mockito::server_url(), "TEST_API_KEY").unwrap();
+
+    let result = tokio_test::block_on(client.query::<Record>("from(bucket: \"missing\")"));
+
+    match result {
+        Err(InfluxError::Csv(CsvError::Flux { message, reference })) => {
+            assert_eq!(message, "failed to execute query: bucket not found");
+            assert_eq!(reference.as_deref(), Some("897"));
+        }
+        other => panic!("unexpected result: {:?}", other),
+    }
+
+    mock.assert();
+}
+
+#[test]
+fn test_client_query_stream() {
+    let body = format!(
+        "{}#datatype,string,string\r\n#group,true,true\r\n#default,,\r\n,error,reference\r\n,\"query terminated: out of memory\",\r\n\r\n",
+        RESPONSE
+    );
+    let mock = mockito::mock("POST", "/api/v2/query")
+        .with_status(200)
+        .match_header("accept", "application/csv")
+        .with_body(body)
+        .expect(1)
+        .create();
+
+    let client = Client::new(mockito::server_url(), "TEST_API_KEY").unwrap();
+
+    let items = tokio_test::block_on(async {
+        let stream = client.query_stream("from(bucket: \"tradely\")").await?;
+        Ok::<_, InfluxError>(stream.collect::<Vec<_>>().await)
+    })
+    .unwrap();
+
+    assert_eq!(items.len(), 4);
+    assert_eq!(
+        items[0].as_ref().unwrap().value(),
+        Some(&Value::Float(420.69))
+    );
+    assert!(items[2].is_ok());
+    match &items[3] {
+        Err(InfluxError::Csv(CsvError::Flux { message, reference })) => {
+            assert_eq!(message, "query terminated: out of memory");
+            assert_eq!(reference, &None);
+        }
+        other => panic!("unexpected item: {:?}", other),
+    }
+
+    mock.assert();
+}
+
+#[test]
+fn test_client_query_stream_status_error() {
+    let mock = mockito::mock("POST", "/api/v2/query")
+        .with_status(401)
+        .with_body(r#"{"code":"unauthorized","message":"unauthorized access"}"#)
+        .create();
+
+    let client = Client::new(mockito::server_url(), "TEST_API_KEY").unwrap();
+
+    let result = tokio_test::block_on(client.query_stream("from(bucket: \"tradely\")"));
+
+    assert!(matches!(result, Err(InfluxError::InvalidCredentials(_))));
+
+    mock.assert();
+}
@@ -0,0 +1,122 @@
+use std::time::Duration;
+
+use influxdb_client::{Client, InfluxError, Point, RetryPolicy, TimestampOptions};
+
+fn client(policy: RetryPolicy) -> Client {
+    Client::new(mockito::server_url(), "TEST_API_KEY")
+        .unwrap()
+        .with_bucket("tradely")
+        .with_retry_policy(policy.with_initial_interval(Duration::from_millis(10)))
+}
+
+fn points() -> Vec<Point> {
+    vec![Point::new("test").field("price", 420.69)]
+}
+
+#[test]
+fn test_client_retry_until_success() {
+    let unavailable = mockito::mock("POST", "/api/v2/write")
+        .match_query(mockito::Matcher::Any)
+        .with_status(503)
+        .with_header("retry-after", "0")
+        .expect(2)
+        .create();
+    let success = mockito::mock("POST", "/api/v2/write")
+        .match_query(mockito::Matcher::Any)
+        .match_body("test price=420.69")
+        .with_status(204)
+        .expect(1)
+        .create();
+
+    let client = client(RetryPolicy::default());
+    let result = tokio_test::block_on(client.insert_points(&points(), TimestampOptions::None));
+
+    assert!(result.is_ok());
+
+    unavailable.assert();
+    success.assert();
+}
+
+#[test]
+fn test_client_retry_exhausted() {
+    let mock = mockito::mock("POST", "/api/v2/write")
+        .match_query(mockito::Matcher::Any)
+        .with_status(429)
+        .with_body("too many requests")
+        .expect(3)
+        .create();
+
+    let client = client(RetryPolicy::default().with_max_attempts(3));
+    let result = tokio_test::block_on(client.insert_points(&points(), TimestampOptions::None));
+
+    match result {
+        Err(InfluxError::RetriesExhausted { attempts, source }) => {
+            assert_eq!(attempts, 3);
+            assert!(matches!(*source, InfluxError::RateLimited(_)));
+        }
+        other => panic!("unexpected result: {:?}", other),
+    }
+
+    mock.assert();
+}
+
+#[test]
+fn test_client_retry_max_elapsed_time() {
+    let mock = mockito::mock("POST", "/api/v2/write")
+        .match_query(mockito::Matcher::Any)
+        .with_status(503)
+        .with_header("retry-after", "60")
+        .expect(1)
+        .create();
+
+    let client =
+        client(RetryPolicy::default().with_max_elapsed_time(Some(Duration::from_secs(10))));
+    let result = tokio_test::block_on(client.insert_points(&points(), TimestampOptions::None));
+
+    assert!(matches!(
+        result,
+        Err(InfluxError::RetriesExhausted { attempts: 1, .. })
+    ));
+
+    mock.assert();
+}
+
+#[test]
+fn test_client_retry_not_retryable() {
+    let mock = mockito::mock("POST", "/api/v2/write")
+        .match_query(mockito::Matcher::Any)
+        .with_status(400)
+        .with_body("unable to parse")
+        .expect(1)
+        .create();
+
+    let client = client(RetryPolicy::default());
+    let result = tokio_test::block_on(client.insert_points(&points(), TimestampOptions::None));
+
+    assert!(matches!(result, Err(InfluxError::InvalidSyntax(_))));
+
+    mock.assert();
+}
+
+#[test]
+fn test_client_retry_huge_backoff() {
+    let mock = mockito::mock("POST", "/api/v2/write")
+        .match_query(mockito::Matcher::Any)
+        .with_status(503)
+        .expect(2)
+        .create();
+
+    // The second backoff exceeds what a Duration holds and must not panic
+    let policy = RetryPolicy::default()
+        .with_max_interval(Duration::MAX)
+        .with_multiplier(1e300);
+    let client = client(policy);
+    let result = tokio_test::block_on(client.insert_points(&points(), TimestampOptions::None));
+
+    assert!(matches!(
+        result,
+        Err(InfluxError::RetriesExhausted { attempts: 2, .. })
+    ));
+
+    mock.assert();
+}
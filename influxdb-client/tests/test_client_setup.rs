@@ -0,0 +1,113 @@
+use std::time::Duration;
+
+use influxdb_client::{Client, InfluxError, Point, Setup, TimestampOptions};
+
+use serde_json::json;
+
+const SETUP_RESPONSE: &str = r#"{
+    "user": {"id": "0a1", "name": "admin", "status": "active"},
+    "org": {"id": "168f31904923e853", "name": "tradely"},
+    "bucket": {"id": "0b3a1fd4d2a0e8a1", "orgID": "168f31904923e853", "name": "prices", "retentionRules": []},
+    "auth": {
+        "id": "06c9d4b2e1a2f000",
+        "token": "NEW_TOKEN",
+        "status": "active",
+        "orgID": "168f31904923e853",
+        "userID": "0a1",
+        "permissions": [{"action": "read", "resource": {"type": "instance"}}]
+    }
+}"#;
+
+#[test]
+fn test_client_setup() {
+    let allowed = mockito::mock("GET", "/api/v2/setup")
+        .with_status(200)
+        .with_body(r#"{"allowed": true}"#)
+        .expect(1)
+        .create();
+    let setup = mockito::mock("POST", "/api/v2/setup")
+        .match_body(mockito::Matcher::Json(json!({
+            "username": "admin",
+            "password": "correct horse",
+            "org": "tradely",
+            "bucket": "prices",
+            "retentionPeriodSeconds": 604800,
+        })))
+        .with_status(201)
+        .with_body(SETUP_RESPONSE)
+        .expect(1)
+        .create();
+    let write = mockito::mock("POST", "/api/v2/write")
+        .match_header("authorization", "Token NEW_TOKEN")
+        .match_query(mockito::Matcher::AllOf(vec![
+            mockito::Matcher::UrlEncoded("bucket".into(), "prices".into()),
+            mockito::Matcher::UrlEncoded("org".into(), "tradely".into()),
+        ]))
+        .with_status(204)
+        .expect(1)
+        .create();
+
+    let client = Client::new(mockito::server_url(), "").unwrap();
+    tokio_test::block_on(async {
+        let client = client
+            .setup(
+                &Setup::new("admin", "tradely", "prices")
+                    .with_password("correct horse")
+                    .with_retention(Duration::from_secs(7 * 86400)),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(client.resolve_org_id().await.unwrap(), "168f31904923e853");
+
+        let points = vec![Point::new("test").field("price", 420.69)];
+        client
+            .insert_points(&points, TimestampOptions::None)
+            .await
+            .unwrap();
+    });
+
+    allowed.assert();
+    setup.assert();
+    write.assert();
+}
+
+#[test]
+fn test_client_setup_without_token() {
+    let allowed = mockito::mock("GET", "/api/v2/setup")
+        .with_status(200)
+        .with_body(r#"{"allowed": true}"#)
+        .expect(1)
+        .create();
+    let setup = mockito::mock("POST", "/api/v2/setup")
+        .with_status(201)
+        .with_body(SETUP_RESPONSE.replace(r#""token": "NEW_TOKEN","#, ""))
+        .expect(1)
+        .create();
+
+    let client = Client::new(mockito::server_url(), "").unwrap();
+    let result = tokio_test::block_on(client.setup(&Setup::new("admin", "tradely", "prices")));
+
+    assert!(matches!(result, Err(InfluxError::MissingToken)));
+
+    allowed.assert();
+    setup.assert();
+}
+
+#[test]
+fn test_client_setup_already_done() {
+    let allowed = mockito::mock("GET", "/api/v2/setup")
+        .with_status(200)
+        .with_body(r#"{"allowed": false}"#)
+        .expect(1)
+        .create();
+    let setup = mockito::mock("POST", "/api/v2/setup").expect(0).create();
+
+    let client = Client::new(mockito::server_url(), "").unwrap();
+    let result = tokio_test::block_on(client.setup(&Setup::new("admin", "tradely", "prices")));
+
+    assert!(matches!(result, Err(InfluxError::AlreadySetUp)));
+
+    allowed.assert();
+    setup.assert();
+}
@@ -0,0 +1,134 @@
+use influxdb_client::{
+    Client, InfluxError, Point, Precision, Series, Timestamp, TimestampOptions, Value,
+};
+
+use mockito::Matcher;
+
+fn client() -> Client {
+    Client::new(mockito::server_url(), "TEST_API_KEY")
+        .unwrap()
+        .with_bucket("ignored")
+        .with_org("ignored")
+}
+
+#[test]
+fn test_client_insert_points_v1() {
+    let mock = mockito::mock("POST", "/write")
+        .match_header("Authorization", "Token TEST_API_KEY")
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("db".into(), "telegraf".into()),
+            Matcher::UrlEncoded("rp".into(), "autogen".into()),
+            Matcher::UrlEncoded("precision".into(), "ms".into()),
+        ]))
+        .match_body("cpu,host=a usage=1.5 1614956250123")
+        .with_status(204)
+        .expect(1)
+        .create();
+
+    let points = vec![Point::new("cpu")
+        .tag("host", "a")
+        .field("usage", 1.5)
+        .timestamp(Timestamp::Precise(1614956250123456789, Precision::NS))];
+    let result = tokio_test::block_on(client().with_precision(Precision::MS).insert_points_v1(
+        "telegraf",
+        Some("autogen"),
+        &points,
+        TimestampOptions::FromPoint,
+    ));
+
+    assert!(result.is_ok(), "{:?}", result);
+    mock.assert();
+}
+
+#[test]
+fn test_client_query_v1() {
+    let mock = mockito::mock("POST", "/query")
+        .match_query(Matcher::UrlEncoded("db".into(), "telegraf".into()))
+        .match_body(Matcher::UrlEncoded(
+            "q".into(),
+            "SELECT mean(usage) FROM cpu GROUP BY host".into(),
+        ))
+        .with_status(200)
+        .with_body(
+            r#"{"results": [{"statement_id": 0, "series": [
+            {"name": "cpu", "tags": {"host": "a"}, "columns": ["time", "mean"],
+             "values": [["2021-03-05T14:57:30Z", 1.5], ["2021-03-05T14:58:30Z", null]]}
+        ]}]}"#,
+        )
+        .expect(1)
+        .create();
+
+    let series = tokio_test::block_on(client().query_v1(
+        "telegraf",
+        None,
+        "SELECT mean(usage) FROM cpu GROUP BY host",
+    ))
+    .unwrap();
+
+    assert_eq!(series[0].columns, vec!["time", "mean"]);
+    assert_eq!(series[0].tags["host"], "a");
+    let points = series[0].to_points();
+    assert_eq!(points.len(), 1);
+    assert_eq!(points[0].tags, vec![("host".to_string(), "a".to_string())]);
+    assert_eq!(
+        points[0].fields,
+        vec![("mean".to_string(), Value::Float(1.5))]
+    );
+    assert_eq!(
+        points[0].timestamp,
+        Some(Timestamp::Precise(1614956250000000000, Precision::NS))
+    );
+
+    mock.assert();
+}
+
+#[test]
+fn test_series_to_points_numbers() {
+    let series: Series = serde_json::from_value(serde_json::json!({
+        "name": "cpu",
+        "columns": ["time", "count", "mean"],
+        "values": [[1614956250000000000i64, 3, 2], [1614956310000000000i64, 4, 2.5]],
+    }))
+    .unwrap();
+
+    // Whole numbers stay floats unless the column is named as integer
+    let points = series.to_points();
+    assert_eq!(
+        points[0].fields,
+        vec![
+            ("count".to_string(), Value::Float(3.0)),
+            ("mean".to_string(), Value::Float(2.0))
+        ]
+    );
+    assert_eq!(
+        points[0].timestamp,
+        Some(Timestamp::Precise(1614956250000000000, Precision::NS))
+    );
+
+    let points = series.to_points_with_integers(&["count"]);
+    assert_eq!(
+        points[1].fields,
+        vec![
+            ("count".to_string(), Value::Int(4)),
+            ("mean".to_string(), Value::Float(2.5))
+        ]
+    );
+}
+
+#[test]
+fn test_client_query_v1_statement_error() {
+    let mock = mockito::mock("POST", "/query")
+        .match_query(Matcher::Any)
+        .with_status(200)
+        .with_body(r#"{"results": [{"statement_id": 0, "error": "database not found: nope"}]}"#)
+        .expect(1)
+        .create();
+
+    let result = tokio_test::block_on(client().query_v1("nope", None, "SELECT * FROM cpu"));
+
+    match result {
+        Err(InfluxError::InfluxQl(message)) => assert_eq!(message, "database not found: nope"),
+        other => panic!("unexpected result {:?}", other),
+    }
+    mock.assert();
+}
@@ -0,0 +1,117 @@
+use influxdb_client::csv::{self, DataType};
+use influxdb_client::{Cell, CsvError, Precision, Timestamp, Value};
+
+use futures::TryStreamExt;
+
+const MULTIPLE_RESULTS: &str = "#datatype,string,long,dateTime:RFC3339Nano,unsignedLong,string\r
+#group,false,false,false,false,true\r
+#default,_result,,,,\r
+,result,table,_time,_value,host\r
+,,0,2021-03-05T15:00:00.5Z,42,a\r
+,,0,2021-03-05T16:00:00+01:00,43,a\r
+,,1,1970-01-01T00:00:00Z,44,b\r
+\r
+#datatype,string,long,boolean,string\r
+#group,false,false,false,false\r
+#default,mean,,,\r
+,result,table,ok,comment\r
+,,0,true,\"multi\r
+line, \"\"quoted\"\"\"\r
+\r
+";
+
+#[test]
+fn test_read_tables() {
+    let tables = csv::read_tables(MULTIPLE_RESULTS.as_bytes()).unwrap();
+
+    assert_eq!(tables.len(), 3);
+
+    assert_eq!(tables[0].result, "_result");
+    assert_eq!(tables[0].id, 0);
+    assert_eq!(tables[0].records.len(), 2);
+    assert_eq!(tables[0].columns.len(), 5);
+    assert_eq!(tables[0].columns[2].data_type, DataType::DateTime);
+    assert_eq!(tables[0].columns[3].data_type, DataType::UnsignedLong);
+    assert!(tables[0].columns[4].group);
+    assert_eq!(tables[0].columns[0].default.as_deref(), Some("_result"));
+
+    let record = &tables[0].records[0];
+    assert_eq!(
+        record.time(),
+        Some(&Timestamp::Precise(1614956400500000000, Precision::NS))
+    );
+    assert_eq!(record.get("_value"), Some(&Cell::Value(Value::UInt(42))));
+    assert_eq!(
+        tables[0].records[1].time(),
+        Some(&Timestamp::Precise(1614956400000000000, Precision::NS))
+    );
+
+    assert_eq!(tables[1].id, 1);
+    assert_eq!(tables[1].columns, tables[0].columns);
+    assert_eq!(
+        tables[1].records[0].time(),
+        Some(&Timestamp::Precise(0, Precision::NS))
+    );
+
+    assert_eq!(tables[2].result, "mean");
+    assert_eq!(tables[2].columns[2].data_type, DataType::Boolean);
+    let record = &tables[2].records[0];
+    assert_eq!(record.value_of("ok"), Some(&Value::Bool(true)));
+    assert_eq!(
+        record.value_of("comment"),
+        Some(&Value::Str("multi\nline, \"quoted\"".into()))
+    );
+}
+
+#[test]
+fn test_read_tables_async() {
+    let tables = tokio_test::block_on(csv::read_tables_async(MULTIPLE_RESULTS.as_bytes())).unwrap();
+
+    assert_eq!(
+        tables,
+        csv::read_tables(MULTIPLE_RESULTS.as_bytes()).unwrap()
+    );
+}
+
+#[test]
+fn test_read_records_async() {
+    let records = tokio_test::block_on(
+        csv::read_records_async(MULTIPLE_RESULTS.as_bytes()).try_collect::<Vec<_>>(),
+    )
+    .unwrap();
+
+    assert_eq!(records.len(), 4);
+    assert_eq!(records[3].result(), Some("mean"));
+}
+
+#[test]
+fn test_read_without_annotations() {
+    let text = ",result,table,_value\n,_result,0,1.5\n";
+
+    let records = csv::Reader::new(text.as_bytes())
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+
+    assert_eq!(
+        records[0].value(),
+        Some(&Value::Str("1.5".into())),
+        "columns without a datatype are strings"
+    );
+}
+
+#[test]
+fn test_read_malformed() {
+    let text = "#datatype,string,long\n,result,table\n,_result,zero\n";
+
+    match csv::read_tables(text.as_bytes()) {
+        Err(CsvError::Malformed { line, .. }) => assert_eq!(line, 3),
+        other => panic!("unexpected result: {:?}", other),
+    }
+
+    let text = "#datatype,string,long\n,result,table\n,\"_result,0\n";
+
+    match csv::read_tables(text.as_bytes()) {
+        Err(CsvError::Malformed { line, .. }) => assert_eq!(line, 3),
+        other => panic!("unexpected result: {:?}", other),
+    }
+}
@@ -0,0 +1,135 @@
+use influxdb_client::derives::FromRecord;
+use influxdb_client::{Cell, FromRecord, Precision, Record, RecordError, Timestamp, Value};
+
+#[derive(FromRecord, Debug, PartialEq)]
+#[point(measurement = "test")]
+struct Test {
+    #[point(tag = "notTicker")]
+    ticker: String,
+    #[point(tag)]
+    exchange: Option<String>,
+    #[point(field = "notPrice")]
+    price: f64,
+    #[point(field)]
+    volume: i32,
+    #[point(timestamp)]
+    time: Timestamp,
+    #[point(skip)]
+    untracked: bool,
+}
+
+fn record(values: Vec<(&str, Cell)>) -> Record {
+    Record {
+        values: values
+            .into_iter()
+            .map(|(name, cell)| (name.to_string(), cell))
+            .collect(),
+    }
+}
+
+#[test]
+fn test_derive_from_pivoted_record() {
+    let record = record(vec![
+        ("_time", Cell::Time(Timestamp::Int(1614956250000000000))),
+        ("_measurement", Cell::Value(Value::Str("test".into()))),
+        ("notTicker", Cell::Value(Value::Str("GME".into()))),
+        ("notPrice", Cell::Value(Value::Float(420.69))),
+        ("volume", Cell::Value(Value::Int(1337))),
+    ]);
+
+    let result = Test::from_record(record).unwrap();
+
+    assert_eq!(
+        result,
+        Test {
+            ticker: "GME".into(),
+            exchange: None,
+            price: 420.69,
+            volume: 1337,
+            time: Timestamp::Int(1614956250000000000),
+            untracked: false,
+        }
+    );
+}
+
+#[test]
+fn test_derive_from_unpivoted_record() {
+    #[derive(FromRecord)]
+    #[point(measurement = "test")]
+    struct Price {
+        #[point(tag)]
+        ticker: String,
+        #[point(field = "price")]
+        value: f64,
+    }
+
+    let record = record(vec![
+        ("_field", Cell::Value(Value::Str("price".into()))),
+        ("_value", Cell::Value(Value::Float(420.69))),
+        ("ticker", Cell::Value(Value::Str("GME".into()))),
+    ]);
+
+    let result = Price::from_record(record).unwrap();
+
+    assert_eq!(result.ticker, "GME");
+    assert_eq!(result.value, 420.69);
+}
+
+#[test]
+fn test_derive_from_record_integer_timestamp() {
+    #[derive(FromRecord)]
+    #[point(measurement = "test")]
+    struct IntTimestamp {
+        #[point(field)]
+        price: f64,
+        #[point(timestamp)]
+        time: i64,
+        #[point(timestamp)]
+        unsigned: Option<u64>,
+    }
+
+    let record = record(vec![
+        (
+            "_time",
+            Cell::Time(Timestamp::Precise(1614956250123000000, Precision::NS)),
+        ),
+        ("price", Cell::Value(Value::Float(420.69))),
+    ]);
+
+    let result = IntTimestamp::from_record(record).unwrap();
+
+    assert_eq!(result.price, 420.69);
+    assert_eq!(result.time, 1614956250123000000);
+    assert_eq!(result.unsigned, Some(1614956250123000000));
+}
+
+#[test]
+fn test_derive_from_record_errors() {
+    let missing = record(vec![
+        ("_time", Cell::Time(Timestamp::Int(0))),
+        ("notTicker", Cell::Value(Value::Str("GME".into()))),
+        ("volume", Cell::Value(Value::Int(1337))),
+    ]);
+
+    match Test::from_record(missing) {
+        Err(RecordError::MissingColumn(column)) => assert_eq!(column, "notPrice"),
+        other => panic!("unexpected result: {:?}", other),
+    }
+
+    let mismatch = record(vec![
+        ("_time", Cell::Time(Timestamp::Int(0))),
+        ("notTicker", Cell::Value(Value::Str("GME".into()))),
+        ("notPrice", Cell::Value(Value::Str("expensive".into()))),
+        ("volume", Cell::Value(Value::Int(1337))),
+    ]);
+
+    match Test::from_record(mismatch) {
+        Err(RecordError::TypeMismatch {
+            column, expected, ..
+        }) => {
+            assert_eq!(column, "notPrice");
+            assert_eq!(expected, "f64");
+        }
+        other => panic!("unexpected result: {:?}", other),
+    }
+}
@@ -1,5 +1,9 @@
 use influxdb_client::derives::PointSerialize;
-use influxdb_client::{PointSerialize, Timestamp};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use influxdb_client::{
+    Client, InfluxError, PointSerialize, Precision, Timestamp, TimestampOptions,
+};
 
 #[test]
 fn test_derive_serialize() {
@@ -70,3 +74,281 @@ fn test_derive_serialize_with_timestamp() {
         result_2
     );
 }
+
+#[test]
+fn test_derive_serialize_matches_point() {
+    use influxdb_client::Point;
+
+    #[derive(PointSerialize)]
+    #[point(measurement = "wea ther")]
+    struct Test {
+        #[point(tag = "loc ation")]
+        location: String,
+        #[point(field)]
+        text: String,
+        #[point(field)]
+        count: i32,
+        #[point(field)]
+        total: u64,
+        #[point(field)]
+        ok: bool,
+        #[point(timestamp)]
+        time: Timestamp,
+    }
+
+    let data = Test {
+        location: "us,mid west".to_string(),
+        text: "too \"hot\"\n".to_string(),
+        count: 3,
+        total: 42,
+        ok: true,
+        time: Timestamp::from(1556813561098000000),
+    };
+
+    let point = Point::new("wea ther")
+        .tag("loc ation", "us,mid west")
+        .field("text", "too \"hot\"\n")
+        .field("count", 3)
+        .field("total", 42u64)
+        .field("ok", true)
+        .timestamp(1556813561098000000);
+
+    assert_eq!(
+        r#"wea\ ther,loc\ ation=us\,mid\ west text="too \"hot\""#.to_string()
+            + "\n\",count=3i,total=42u,ok=true 1556813561098000000",
+        data.serialize_with_timestamp(None)
+    );
+    assert_eq!(
+        point.serialize_with_timestamp(None),
+        data.serialize_with_timestamp(None)
+    );
+}
+
+#[derive(PointSerialize)]
+#[point(measurement = "test")]
+struct Optional {
+    #[point(tag)]
+    ticker: Option<String>,
+    #[point(tag)]
+    exchange: String,
+    #[point(field)]
+    price: Option<f64>,
+    #[point(field)]
+    volume: Option<i64>,
+    #[point(skip)]
+    #[allow(dead_code)]
+    note: String,
+    #[point(timestamp)]
+    time: Timestamp,
+}
+
+#[test]
+fn test_derive_serialize_optional() {
+    let data = Optional {
+        ticker: None,
+        exchange: "NYSE".to_string(),
+        price: Some(420.69),
+        volume: None,
+        note: "not written".to_string(),
+        time: Timestamp::from(1),
+    };
+
+    assert_eq!(
+        data.serialize_with_timestamp(None),
+        "test,exchange=NYSE price=420.69 1"
+    );
+
+    let data = Optional {
+        ticker: Some("GME".to_string()),
+        volume: Some(10),
+        ..data
+    };
+
+    assert_eq!(
+        data.serialize_with_timestamp(None),
+        "test,ticker=GME,exchange=NYSE price=420.69,volume=10i 1"
+    );
+}
+
+#[test]
+fn test_derive_serialize_without_fields() {
+    let data = Optional {
+        ticker: Some("GME".to_string()),
+        exchange: "NYSE".to_string(),
+        price: None,
+        volume: None,
+        note: String::new(),
+        time: Timestamp::from(1),
+    };
+
+    assert!(matches!(
+        data.validate(),
+        Err(InfluxError::NoFields(measurement)) if measurement == "test"
+    ));
+
+    let client = Client::new("http://localhost:8086", "TEST_API_KEY").unwrap();
+    let result = tokio_test::block_on(client.insert_points(&[data], TimestampOptions::FromPoint));
+
+    assert!(matches!(result, Err(InfluxError::NoFields(_))));
+}
+
+#[test]
+fn test_derive_serialize_timestamp_types() {
+    #[derive(PointSerialize)]
+    #[point(measurement = "test")]
+    struct NoTimestamp {
+        #[point(field)]
+        price: f64,
+    }
+
+    #[derive(PointSerialize)]
+    #[point(measurement = "test")]
+    struct OptionalTimestamp {
+        #[point(field)]
+        price: f64,
+        #[point(timestamp)]
+        time: Option<Timestamp>,
+    }
+
+    #[derive(PointSerialize)]
+    #[point(measurement = "test")]
+    struct SystemTimestamp {
+        #[point(field)]
+        price: f64,
+        #[point(timestamp)]
+        time: SystemTime,
+    }
+
+    #[derive(PointSerialize)]
+    #[point(measurement = "test")]
+    struct IntTimestamp {
+        #[point(field)]
+        price: f64,
+        #[point(timestamp)]
+        time: u64,
+    }
+
+    let time = UNIX_EPOCH + Duration::from_millis(1614956250123);
+
+    assert_eq!(
+        NoTimestamp { price: 1.0 }
+            .serialize_with_precision(None, Precision::MS, false)
+            .unwrap(),
+        "test price=1"
+    );
+    assert_eq!(
+        OptionalTimestamp {
+            price: 1.0,
+            time: None
+        }
+        .serialize_with_precision(None, Precision::MS, false)
+        .unwrap(),
+        "test price=1"
+    );
+    assert_eq!(
+        OptionalTimestamp {
+            price: 1.0,
+            time: Some(Timestamp::from(42))
+        }
+        .serialize_with_precision(None, Precision::MS, false)
+        .unwrap(),
+        "test price=1 42"
+    );
+    assert_eq!(
+        SystemTimestamp { price: 1.0, time }
+            .serialize_with_precision(None, Precision::MS, false)
+            .unwrap(),
+        "test price=1 1614956250123"
+    );
+    assert_eq!(
+        SystemTimestamp { price: 1.0, time }
+            .serialize_with_precision(None, Precision::S, false)
+            .unwrap(),
+        "test price=1 1614956250"
+    );
+    assert_eq!(
+        SystemTimestamp { price: 1.0, time }.serialize_with_timestamp(None),
+        "test price=1 1614956250123000000"
+    );
+    assert_eq!(
+        IntTimestamp {
+            price: 1.0,
+            time: 1614956250
+        }
+        .serialize_with_precision(None, Precision::S, false)
+        .unwrap(),
+        "test price=1 1614956250"
+    );
+
+    let far = UNIX_EPOCH + Duration::from_secs(10_000_000_000);
+    assert!(matches!(
+        SystemTimestamp {
+            price: 1.0,
+            time: far
+        }
+        .serialize_with_precision(None, Precision::NS, false),
+        Err(InfluxError::TimestampOutOfRange(Precision::NS))
+    ));
+    assert!(matches!(
+        IntTimestamp {
+            price: 1.0,
+            time: u64::MAX
+        }
+        .serialize_with_precision(None, Precision::S, false),
+        Err(InfluxError::TimestampOutOfRange(Precision::S))
+    ));
+    assert_eq!(
+        SystemTimestamp {
+            price: 1.0,
+            time: far
+        }
+        .serialize_with_timestamp(None),
+        "test price=1"
+    );
+    // An explicit timestamp replaces the one out of range
+    assert_eq!(
+        SystemTimestamp {
+            price: 1.0,
+            time: far
+        }
+        .serialize_with_precision(Some(Timestamp::from(42)), Precision::NS, false)
+        .unwrap(),
+        "test price=1 42"
+    );
+}
+
+#[test]
+fn test_derive_insert_with_client_precision() {
+    #[derive(PointSerialize)]
+    #[point(measurement = "test")]
+    struct Test {
+        #[point(field)]
+        price: f64,
+        #[point(timestamp)]
+        time: SystemTime,
+    }
+
+    let mock = mockito::mock("POST", "/api/v2/write")
+        .match_query(mockito::Matcher::UrlEncoded(
+            "precision".into(),
+            "us".into(),
+        ))
+        .match_body("test price=420.69 1614956250123000")
+        .with_status(204)
+        .expect(1)
+        .create();
+
+    let client = Client::new(mockito::server_url(), "TEST_API_KEY")
+        .unwrap()
+        .with_bucket("tradely")
+        .with_precision(Precision::US);
+    let points = vec![Test {
+        price: 420.69,
+        time: UNIX_EPOCH + Duration::from_millis(1614956250123),
+    }];
+    let result = tokio_test::block_on(client.insert_points(&points, TimestampOptions::FromPoint));
+
+    assert!(result.is_ok());
+
+    mock.assert();
+}
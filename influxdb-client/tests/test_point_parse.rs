@@ -0,0 +1,134 @@
+use influxdb_client::{parse_lines, ParseError, Point, PointSerialize, Timestamp, Value};
+
+#[test]
+fn test_point_parse() {
+    let point: Point = "mem,host=host1 used_percent=23.43234543 1556813561098000000"
+        .parse()
+        .unwrap();
+
+    let expected = Point::new("mem")
+        .tag("host", "host1")
+        .field("used_percent", 23.43234543)
+        .timestamp(1556813561098000000);
+
+    assert_eq!(point, expected);
+}
+
+#[test]
+fn test_point_parse_without_timestamp() {
+    let point: Point = "mem name=\"Julius\"".parse().unwrap();
+
+    assert_eq!(point, Point::new("mem").field("name", "Julius"));
+    assert_eq!(point.timestamp, None);
+}
+
+#[test]
+fn test_point_parse_all_types() {
+    let point: Point =
+        r#"mem float=2.345,exp=-1.5e3,int=-42i,uint=42u,str="Hello \"world\" \\o/",t=t,tt=True,f=FALSE -9223372036854775806"#
+            .parse()
+            .unwrap();
+
+    assert_eq!(
+        point.fields,
+        vec![
+            ("float".to_string(), Value::Float(2.345)),
+            ("exp".to_string(), Value::Float(-1500.0)),
+            ("int".to_string(), Value::Int(-42)),
+            ("uint".to_string(), Value::UInt(42)),
+            (
+                "str".to_string(),
+                Value::Str("Hello \"world\" \\o/".to_string())
+            ),
+            ("t".to_string(), Value::Bool(true)),
+            ("tt".to_string(), Value::Bool(true)),
+            ("f".to_string(), Value::Bool(false)),
+        ]
+    );
+    assert_eq!(point.timestamp, Some(Timestamp::Int(-9223372036854775806)));
+}
+
+#[test]
+fn test_point_parse_escapes() {
+    let line = r#"wea\,ther\ now,loc\=ation=us\,mid\ west temp\=rature=82,text="a, b=c" 1"#;
+
+    let point: Point = line.parse().unwrap();
+
+    assert_eq!(point.measurement, "wea,ther now");
+    assert_eq!(
+        point.tags,
+        vec![("loc=ation".to_string(), "us,mid west".to_string())]
+    );
+    assert_eq!(point.fields[0].0, "temp=rature");
+    assert_eq!(point.fields[1].1, Value::Str("a, b=c".to_string()));
+    assert_eq!(point.serialize_with_timestamp(None), line);
+}
+
+#[test]
+fn test_parse_lines() {
+    let text = "# comment\n\nmem,host=a used=1 1\r\n  \tcpu idle=99.5\n";
+
+    let points = parse_lines(text).unwrap();
+
+    assert_eq!(points.len(), 2);
+    assert_eq!(points[0].measurement, "mem");
+    assert_eq!(points[1].measurement, "cpu");
+}
+
+#[test]
+fn test_parse_lines_errors() {
+    let cases = vec![
+        ("mem", 1, 4, "missing fields"),
+        ("mem,host used=1", 1, 9, "expected '=' after tag key 'host'"),
+        ("mem,host= used=1", 1, 10, "missing value of tag 'host'"),
+        ("mem used=", 1, 10, "missing value of field 'used'"),
+        (
+            "mem used=abc",
+            1,
+            10,
+            "invalid value of field 'used': 'abc'",
+        ),
+        (
+            "mem used=1.5i",
+            1,
+            10,
+            "invalid value of field 'used': '1.5i'",
+        ),
+        ("mem used=\"open", 1, 10, "unterminated string"),
+        ("mem used=1 now", 1, 12, "invalid timestamp 'now'"),
+        ("mem used=1 1 2", 1, 13, "unexpected trailing characters"),
+        ("  mem used=", 1, 12, "missing value of field 'used'"),
+        (
+            "cpu idle=1\nmem used=NaN",
+            2,
+            10,
+            "invalid value of field 'used': 'NaN'",
+        ),
+    ];
+
+    for (text, line, column, message) in cases {
+        assert_eq!(
+            parse_lines(text),
+            Err(ParseError {
+                line,
+                column,
+                message: message.to_string()
+            }),
+            "{}",
+            text
+        );
+    }
+}
+
+#[test]
+fn test_point_serialize_parse_round_trip() {
+    let point = Point::new("files")
+        .tag("host", "win 1")
+        .field("path", r"C:\temp\")
+        .field("quoted", r#"say "hi" \o/"#)
+        .timestamp(1);
+
+    let parsed = parse_lines(&point.serialize_with_timestamp(None)).unwrap();
+
+    assert_eq!(parsed, vec![point]);
+}
@@ -14,6 +14,17 @@ fn test_point_serialize_with_timestamp_from_point() {
     assert_eq!(actual, expected);
 }
 
+#[test]
+fn test_point_serialize_without_timestamp() {
+    let point = Point::new("mem").field("used_percent", 23.5);
+
+    assert_eq!(
+        point.serialize_with_timestamp(None),
+        "mem used_percent=23.5 0"
+    );
+    assert_eq!(point.serialize(), "mem used_percent=23.5");
+}
+
 #[test]
 fn test_point_serialize_with_timestamp() {
     let expected = "mem,host=host1,origin=origin1 used_percent=23.43234543 420";
@@ -68,13 +79,13 @@ fn test_point_serialize_only_measurement() {
 
 #[test]
 fn test_point_serialize_all_types() {
-    let expected = r#"mem,tag_string=Hello\ world\ :D float=2.345,bool=true,int=-9223372036854775806,field_string="Hello world :D\"" -9223372036854775806"#;
+    let expected = r#"mem,tag_string=Hello\ world\ :D float=2.345,bool=true,int=-9223372036854775806i,field_string="Hello world :D\"" -9223372036854775806"#;
 
     let point = Point::new("mem")
         .tag("tag_string", "Hello world :D")
         .field("float", 2.345)
         .field("bool", true)
-        .field("int", -9223372036854775806)
+        .field("int", -9223372036854775806i64)
         .field("field_string", "Hello world :D\"")
         .timestamp(-9223372036854775806);
 
@@ -83,6 +94,24 @@ fn test_point_serialize_all_types() {
     assert_eq!(actual, expected);
 }
 
+#[test]
+fn test_point_serialize_integers() {
+    let expected = "mem i8=-8i,i32=-32i,isize=-1i,u8=8u,u32=32u,u64=18446744073709551615u,f32=0.32";
+
+    let point = Point::new("mem")
+        .field("i8", -8i8)
+        .field("i32", -32)
+        .field("isize", -1isize)
+        .field("u8", 8u8)
+        .field("u32", 32u32)
+        .field("u64", u64::MAX)
+        .field("f32", 0.32f32);
+
+    let actual = point.serialize();
+
+    assert_eq!(actual, expected);
+}
+
 #[test]
 fn test_point_serialize_emojies() {
     let expected = "mem,tagKey=🍭 fieldKey=\"Launch 🚀\" 1556813561098000000";
@@ -0,0 +1,201 @@
+use std::convert::TryFrom;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use influxdb_client::derives::FromRecord;
+use influxdb_client::{
+    Cell, Client, FromCell, FromRecord, InfluxError, Point, Precision, Record, Timestamp,
+    TimestampOptions, Value,
+};
+
+#[test]
+fn test_timestamp_from_system_time() {
+    let time = UNIX_EPOCH + Duration::from_nanos(1614956250123456789);
+
+    assert_eq!(
+        Timestamp::try_from(time).unwrap(),
+        Timestamp::Precise(1614956250123456789, Precision::NS)
+    );
+    assert_eq!(
+        Timestamp::from_system_time(time, Precision::US).unwrap(),
+        Timestamp::Precise(1614956250123456, Precision::US)
+    );
+    assert_eq!(
+        Timestamp::from_system_time(time, Precision::S).unwrap(),
+        Timestamp::Precise(1614956250, Precision::S)
+    );
+
+    // Rounded down, also before the epoch
+    let before = UNIX_EPOCH - Duration::from_millis(1500);
+    assert_eq!(
+        Timestamp::from_system_time(before, Precision::S).unwrap(),
+        Timestamp::Precise(-2, Precision::S)
+    );
+}
+
+#[test]
+fn test_timestamp_from_system_time_out_of_range() {
+    // 2286, after the last nanosecond timestamp in 2262
+    let time = UNIX_EPOCH + Duration::from_secs(10_000_000_000);
+
+    assert!(matches!(
+        Timestamp::try_from(time),
+        Err(InfluxError::TimestampOutOfRange(Precision::NS))
+    ));
+    assert_eq!(
+        Timestamp::from_system_time(time, Precision::MS).unwrap(),
+        Timestamp::Precise(10_000_000_000_000, Precision::MS)
+    );
+}
+
+#[test]
+fn test_timestamp_to_system_time() {
+    let time = UNIX_EPOCH + Duration::from_millis(1614956250123);
+
+    assert_eq!(
+        Timestamp::Int(1614956250123).to_system_time(Precision::MS),
+        Some(time)
+    );
+    assert_eq!(
+        Timestamp::from("2021-03-05T14:57:30.123Z").to_system_time(Precision::NS),
+        Some(time)
+    );
+    assert_eq!(
+        Timestamp::from("1614956250123").to_system_time(Precision::MS),
+        Some(time)
+    );
+    assert_eq!(
+        Timestamp::from("yesterday").to_system_time(Precision::MS),
+        None
+    );
+}
+
+#[test]
+fn test_timestamp_parse_rfc3339() {
+    assert_eq!(
+        Timestamp::parse_rfc3339("2021-03-05T15:57:30.123456789+01:00"),
+        Some(Timestamp::Precise(1614956250123456789, Precision::NS))
+    );
+    assert_eq!(Timestamp::parse_rfc3339("2021-03-05"), None);
+}
+
+#[test]
+fn test_timestamp_parse_rfc3339_invalid() {
+    let cases = [
+        "2021-03-05T15:57:99Z",
+        "2021-03-05T15:57:30.Z",
+        "2021-03-05T15:57:30+1:000",
+        // multi-byte characters on the positions of separators and digits
+        "2021€03-05T15:57:30Z",
+        "2021-03-05T15:5€:30Z",
+        "2021-03-05T15:57:30.12€Z",
+        "2021-03-05T15:57:30+€:00",
+        "2021-03-05T15:57:30+01€00",
+    ];
+
+    for case in cases {
+        assert_eq!(Timestamp::parse_rfc3339(case), None, "{}", case);
+    }
+}
+
+#[test]
+fn test_system_time_from_cell() {
+    let time = UNIX_EPOCH + Duration::from_secs(1614956250);
+
+    assert_eq!(
+        SystemTime::from_cell(&Cell::Time(Timestamp::Int(1614956250000000000))),
+        Some(time)
+    );
+    assert_eq!(
+        SystemTime::from_cell(&Cell::Value(Value::Str("2021-03-05T14:57:30Z".into()))),
+        Some(time)
+    );
+    assert_eq!(SystemTime::from_cell(&Cell::Value(Value::Int(1))), None);
+}
+
+#[test]
+fn test_derive_from_record_system_time() {
+    #[derive(FromRecord)]
+    struct Price {
+        #[point(timestamp)]
+        time: SystemTime,
+    }
+
+    let record = Record {
+        values: vec![(
+            "_time".to_string(),
+            Cell::Time(Timestamp::Int(1614956250000000000)),
+        )],
+    };
+
+    let price = Price::from_record(record).unwrap();
+
+    assert_eq!(price.time, UNIX_EPOCH + Duration::from_secs(1614956250));
+}
+
+#[test]
+fn test_timestamp_rescale() {
+    let timestamp = Timestamp::Precise(1614956250123, Precision::MS);
+
+    assert_eq!(
+        timestamp.rescale(Precision::NS, true).unwrap(),
+        Timestamp::Precise(1614956250123000000, Precision::NS)
+    );
+    assert_eq!(
+        timestamp.rescale(Precision::S, false).unwrap(),
+        Timestamp::Precise(1614956250, Precision::S)
+    );
+    assert!(matches!(
+        timestamp.rescale(Precision::S, true),
+        Err(InfluxError::TimestampPrecision {
+            precision: Precision::S,
+            ..
+        })
+    ));
+    assert!(matches!(
+        Timestamp::Precise(i64::MAX, Precision::S).rescale(Precision::NS, false),
+        Err(InfluxError::TimestampPrecision { .. })
+    ));
+    assert_eq!(
+        Timestamp::Int(1614956250123456789)
+            .rescale(Precision::S, true)
+            .unwrap(),
+        Timestamp::Int(1614956250123456789)
+    );
+}
+
+fn insert_with_precision(
+    precision: Precision,
+    strict: bool,
+    body: &str,
+) -> Result<(), InfluxError> {
+    let mock = mockito::mock("POST", "/api/v2/write")
+        .match_query(mockito::Matcher::Any)
+        .match_body(body)
+        .with_status(204)
+        .create();
+
+    let client = Client::new(mockito::server_url(), "TEST_API_KEY")
+        .unwrap()
+        .with_bucket("tradely")
+        .with_precision(precision)
+        .with_strict_timestamps(strict);
+    let points = vec![Point::new("test")
+        .field("price", 420.69)
+        .timestamp(Timestamp::Precise(1614956250123456789, Precision::NS))];
+    let result = tokio_test::block_on(client.insert_points(&points, TimestampOptions::FromPoint));
+
+    drop(mock);
+    result
+}
+
+#[test]
+fn test_client_rescales_timestamps() {
+    assert!(
+        insert_with_precision(Precision::NS, true, "test price=420.69 1614956250123456789").is_ok()
+    );
+    assert!(insert_with_precision(Precision::MS, false, "test price=420.69 1614956250123").is_ok());
+    assert!(matches!(
+        insert_with_precision(Precision::MS, true, "test price=420.69 1614956250123"),
+        Err(InfluxError::TimestampPrecision { .. })
+    ));
+}
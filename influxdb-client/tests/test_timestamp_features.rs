@@ -0,0 +1,105 @@
+#![cfg(all(feature = "chrono", feature = "time"))]
+
+use std::convert::TryFrom;
+
+use influxdb_client::derives::PointSerialize;
+use influxdb_client::{Cell, FromCell, PointSerialize, Precision, Timestamp, Value};
+
+#[test]
+fn test_derive_chrono_and_time_timestamps() {
+    #[derive(PointSerialize)]
+    #[point(measurement = "test")]
+    struct WithChrono {
+        #[point(field)]
+        price: f64,
+        #[point(timestamp)]
+        time: chrono::DateTime<chrono::Utc>,
+    }
+
+    #[derive(PointSerialize)]
+    #[point(measurement = "test")]
+    struct WithTime {
+        #[point(field)]
+        price: f64,
+        #[point(timestamp)]
+        time: Option<time::OffsetDateTime>,
+    }
+
+    let nanos = 1614956250123456789;
+
+    let point = WithChrono {
+        price: 1.0,
+        time: chrono::DateTime::from_timestamp_nanos(nanos as i64),
+    };
+    assert_eq!(
+        point
+            .serialize_with_precision(None, Precision::NS, false)
+            .unwrap(),
+        "test price=1 1614956250123456789"
+    );
+    assert_eq!(
+        point
+            .serialize_with_precision(None, Precision::MS, false)
+            .unwrap(),
+        "test price=1 1614956250123"
+    );
+
+    let point = WithTime {
+        price: 1.0,
+        time: Some(time::OffsetDateTime::from_unix_timestamp_nanos(nanos).unwrap()),
+    };
+    assert_eq!(
+        point
+            .serialize_with_precision(None, Precision::US, false)
+            .unwrap(),
+        "test price=1 1614956250123456"
+    );
+}
+
+#[test]
+fn test_timestamp_chrono() {
+    let time = chrono::DateTime::parse_from_rfc3339("2021-03-05T15:57:30.123456789+01:00").unwrap();
+
+    assert_eq!(
+        Timestamp::try_from(time).unwrap(),
+        Timestamp::Precise(1614956250123456789, Precision::NS)
+    );
+    assert_eq!(
+        Timestamp::from_chrono(&time, Precision::MS).unwrap(),
+        Timestamp::Precise(1614956250123, Precision::MS)
+    );
+    assert_eq!(
+        Timestamp::Int(1614956250123).to_chrono(Precision::MS),
+        Some(chrono::DateTime::from_timestamp_millis(1614956250123).unwrap())
+    );
+    assert_eq!(
+        chrono::DateTime::<chrono::Utc>::from_cell(&Cell::Time(Timestamp::Int(
+            1614956250123456789
+        ))),
+        Some(time.with_timezone(&chrono::Utc))
+    );
+}
+
+#[test]
+fn test_timestamp_time() {
+    let time = time::OffsetDateTime::from_unix_timestamp_nanos(1614956250123456789).unwrap();
+
+    assert_eq!(
+        Timestamp::try_from(time).unwrap(),
+        Timestamp::Precise(1614956250123456789, Precision::NS)
+    );
+    assert_eq!(
+        Timestamp::from_offset_date_time(time, Precision::S).unwrap(),
+        Timestamp::Precise(1614956250, Precision::S)
+    );
+    assert_eq!(
+        Timestamp::Int(1614956250123456789).to_offset_date_time(Precision::NS),
+        Some(time)
+    );
+    assert_eq!(
+        time::OffsetDateTime::from_cell(&Cell::Value(Value::Str(
+            "2021-03-05T14:57:30.123456789Z".to_string()
+        ))),
+        Some(time)
+    );
+}
@@ -2,7 +2,6 @@ extern crate proc_macro;
 
 use proc_macro::TokenStream;
 use quote::{quote, quote_spanned};
-use syn::spanned::Spanned;
 
 use proc_macro_roids::{namespace_parameter, DeriveInputStructExt, FieldExt};
 
@@ -10,6 +9,7 @@ use proc_macro_roids::{namespace_parameter, DeriveInputStructExt, FieldExt};
 pub fn point_serialize_derive(input: TokenStream) -> TokenStream {
     // Paths
     let namespace: syn::Path = syn::parse_quote!(point);
+    let skip_path: syn::Path = syn::parse_quote!(skip);
     let field_path: syn::Path = syn::parse_quote!(field);
     let tag_path: syn::Path = syn::parse_quote!(tag);
     let timestamp_path: syn::Path = syn::parse_quote!(timestamp);
@@ -40,119 +40,229 @@ pub fn point_serialize_derive(input: TokenStream) -> TokenStream {
     };
 
     let ast_fields = ast.fields();
-
-    macro_rules! field_splitter {
-        ($names:ident, $tokens:ident, $field:ident) => {
-            let ident: &syn::Ident = &$field.ident.as_ref().unwrap();
-            let field_name: String =
-                if let syn::NestedMeta::Meta(syn::Meta::NameValue(syn::MetaNameValue {
-                    lit, ..
-                })) = namespace_parameter(&$field.attrs, &namespace).unwrap()
-                {
-                    if let syn::Lit::Str(lit_str) = lit {
-                        lit_str.value()
-                    } else {
-                        let span = lit.span();
-                        return (quote_spanned! { span => compile_error!("Attribute must be a string type"); })
-                            .into();
-                    }
-                } else {
-                    ident.to_string()
-                };
-            $names.push(field_name);
-            $tokens.push(ident);
-        };
-    }
-
-    macro_rules! string_vec_joiner {
-        ($vec:ident, $quotes:expr) => {
-            $vec.iter()
-                .map(|it| {
-                    if $quotes {
-                        format!("{}={{:?}}", it)
-                    } else {
-                        format!("{}={{}}", it)
-                    }
-                })
-                .collect::<Vec<String>>()
-                .join(",")
-        };
-    }
+    let included_fields = ast_fields
+        .iter()
+        .filter(|field| !field.contains_tag(&namespace, &skip_path))
+        .collect::<Vec<&syn::Field>>();
 
     // Field-level
-    let mut field_names: Vec<String> = Vec::new();
-    let mut field_tokens: Vec<&syn::Ident> = Vec::new();
-    let fields = ast_fields
+    let fields = included_fields
         .iter()
-        .filter(|field| field.contains_tag(&namespace, &field_path));
-    if fields.clone().count() == 0 {
+        .filter(|field| field.contains_tag(&namespace, &field_path))
+        .collect::<Vec<_>>();
+    if fields.is_empty() {
         return (quote!{ compile_error!("Fields are not optional, there needs to be atleast one!"); }).into();
     }
+    let mut field_statements = Vec::new();
     for field in fields {
-        field_splitter!(field_names, field_tokens, field);
+        let ident: &syn::Ident = field.ident.as_ref().unwrap();
+        let field_name = match attribute_name(field, &namespace) {
+            Ok(field_name) => field_name,
+            Err(error) => return error,
+        };
+        let to_value = quote! { ::influxdb_client::Value::from(::std::clone::Clone::clone(value)) };
+        field_statements.push(if is_option(&field.ty) {
+            quote! {
+                if let ::std::option::Option::Some(value) = &v.#ident {
+                    point = point.field(#field_name, #to_value);
+                }
+            }
+        } else {
+            quote! {
+                let value = &v.#ident;
+                point = point.field(#field_name, #to_value);
+            }
+        });
     }
-    let field_names_combined = string_vec_joiner!(field_names, true);
 
-    let mut tag_names: Vec<String> = Vec::new();
-    let mut tag_tokens: Vec<&syn::Ident> = Vec::new();
-    for field in ast_fields
+    let mut tag_statements = Vec::new();
+    for field in included_fields
         .iter()
         .filter(|field| field.contains_tag(&namespace, &tag_path))
     {
-        field_splitter!(tag_names, tag_tokens, field);
+        let ident: &syn::Ident = field.ident.as_ref().unwrap();
+        let tag_name = match attribute_name(field, &namespace) {
+            Ok(tag_name) => tag_name,
+            Err(error) => return error,
+        };
+        tag_statements.push(if is_option(&field.ty) {
+            quote! {
+                if let ::std::option::Option::Some(value) = &v.#ident {
+                    point = point.tag(#tag_name, ::std::string::ToString::to_string(value));
+                }
+            }
+        } else {
+            quote! {
+                point = point.tag(#tag_name, ::std::string::ToString::to_string(&v.#ident));
+            }
+        });
     }
-    let tag_names_combined = string_vec_joiner!(tag_names, false);
-
-    let complete_text = if tag_names_combined.is_empty() {
-        format!("{{}} {}", field_names_combined)
-    } else {
-        format!("{{}},{} {}", tag_names_combined, field_names_combined)
-    };
 
-    let timestamp = ast_fields
+    // The timestamp is optional and may be any type implementing ToTimestamp
+    let timestamp_statement = included_fields
         .iter()
         .find(|field| field.contains_tag(&namespace, &timestamp_path))
-        .expect("Missing timestamp field! Use #[point(timestamp)] over the timestamp field");
+        .map(|field| {
+            let ident = field.ident.as_ref().unwrap();
+            quote! {
+                point.timestamp = ::influxdb_client::ToTimestamp::to_timestamp(&v.#ident, precision)?;
+            }
+        });
 
-    let timestamp_field = &timestamp.ty;
-    if "Timestamp" != quote! { #timestamp_field }.to_string() {
-        let span = timestamp.ty.span();
-        return (quote_spanned! { span => compile_error!("Timestamp field must have 'Timestamp' type!"); })
-            .into();
-    }
+    // Output
+    // Build a Point and serialize that, so derived and hand-built points share escaping and value typing
+    (quote! {
+        const _: () = {
+            fn to_point(v: &#name) -> ::influxdb_client::Point {
+                let mut point = ::influxdb_client::Point::new(#measurement);
+                #(#tag_statements)*
+                #(#field_statements)*
+                point
+            }
 
-    let struct_timestamp = timestamp.ident.as_ref().unwrap();
+            #[allow(unused_variables)]
+            fn to_point_with_timestamp(
+                v: &#name,
+                precision: ::influxdb_client::Precision,
+            ) -> ::std::result::Result<::influxdb_client::Point, ::influxdb_client::InfluxError> {
+                let mut point = to_point(v);
+                #timestamp_statement
+                ::std::result::Result::Ok(point)
+            }
 
-    let tag_tokens_length = tag_tokens.len();
+            impl ::influxdb_client::PointSerialize for #name {
+                fn serialize(&self) -> ::std::string::String {
+                    ::influxdb_client::PointSerialize::serialize(&to_point(self))
+                }
 
-    let serialize_with_timestamp = quote! {
-        fn serialize_with_timestamp(&self, timestamp: Option<Timestamp>) -> String {
-            match timestamp {
-                Some(timestamp) => format!("{} {}", self.serialize(), timestamp.to_string()),
-                None => format!("{} {}", self.serialize(), self.#struct_timestamp.to_string())
-            }
-        }
-    };
+                fn serialize_with_timestamp(
+                    &self,
+                    timestamp: ::std::option::Option<::influxdb_client::Timestamp>,
+                ) -> ::std::string::String {
+                    let point = match timestamp {
+                        ::std::option::Option::Some(_) => to_point(self),
+                        // A timestamp field out of range for nanoseconds is left out
+                        ::std::option::Option::None => to_point_with_timestamp(self, ::influxdb_client::Precision::NS)
+                            .unwrap_or_else(|_| to_point(self)),
+                    };
+                    // Without any timestamp the server sets the time
+                    if timestamp.is_none() && point.timestamp.is_none() {
+                        return ::influxdb_client::PointSerialize::serialize(&point);
+                    }
+                    ::influxdb_client::PointSerialize::serialize_with_timestamp(&point, timestamp)
+                }
 
-    // Output
-    (if tag_tokens_length != 0 {
-        quote! {
-            impl PointSerialize for #name {
-                fn serialize(&self) -> String {
-                    format!(#complete_text, #measurement, #(self.#tag_tokens),*, #(self.#field_tokens),*).to_string()
+                fn serialize_with_precision(
+                    &self,
+                    timestamp: ::std::option::Option<::influxdb_client::Timestamp>,
+                    precision: ::influxdb_client::Precision,
+                    strict: bool,
+                ) -> ::std::result::Result<::std::string::String, ::influxdb_client::InfluxError> {
+                    let point = match timestamp {
+                        ::std::option::Option::Some(_) => to_point(self),
+                        ::std::option::Option::None => to_point_with_timestamp(self, precision)?,
+                    };
+                    if timestamp.is_none() && point.timestamp.is_none() {
+                        return ::std::result::Result::Ok(::influxdb_client::PointSerialize::serialize(&point));
+                    }
+                    ::influxdb_client::PointSerialize::serialize_with_precision(
+                        &point,
+                        timestamp,
+                        precision,
+                        strict,
+                    )
                 }
-                #serialize_with_timestamp
-            }
-        }
-    } else {
-        quote! {
-            impl PointSerialize for #name {
-                fn serialize(&self) -> String {
-                    format!(#complete_text, #measurement, #(self.#field_tokens),*).to_string()
+
+                fn validate(&self) -> ::std::result::Result<(), ::influxdb_client::InfluxError> {
+                    ::influxdb_client::PointSerialize::validate(&to_point(self))
                 }
-                #serialize_with_timestamp
+            }
+        };
+    })
+    .into()
+}
+
+#[proc_macro_derive(FromRecord, attributes(point))]
+pub fn from_record_derive(input: TokenStream) -> TokenStream {
+    // Paths
+    let namespace: syn::Path = syn::parse_quote!(point);
+    let skip_path: syn::Path = syn::parse_quote!(skip);
+    let field_path: syn::Path = syn::parse_quote!(field);
+    let tag_path: syn::Path = syn::parse_quote!(tag);
+    let timestamp_path: syn::Path = syn::parse_quote!(timestamp);
+
+    let ast = syn::parse_macro_input!(input as syn::DeriveInput);
+    let name = &ast.ident;
+
+    let mut initializers = Vec::new();
+    for field in ast.fields() {
+        let ident: &syn::Ident = field.ident.as_ref().unwrap();
+
+        let initializer = if field.contains_tag(&namespace, &skip_path) {
+            quote! { ::std::default::Default::default() }
+        } else if field.contains_tag(&namespace, &tag_path) {
+            let column = match attribute_name(field, &namespace) {
+                Ok(column) => column,
+                Err(error) => return error,
+            };
+            quote! { record.decode(#column)? }
+        } else if field.contains_tag(&namespace, &field_path) {
+            let column = match attribute_name(field, &namespace) {
+                Ok(column) => column,
+                Err(error) => return error,
+            };
+            quote! { record.decode_field(#column)? }
+        } else if field.contains_tag(&namespace, &timestamp_path) {
+            quote! { record.decode_time("_time")? }
+        } else {
+            // Defaulting silently would hide misspelled attributes
+            let span = ident.span();
+            return (quote_spanned! { span => compile_error!("Field needs one of #[point(tag)], #[point(field)], #[point(timestamp)] or #[point(skip)]"); }).into();
+        };
+        initializers.push(quote! { #ident: #initializer });
+    }
+
+    (quote! {
+        impl ::influxdb_client::FromRecord for #name {
+            fn from_record(
+                record: ::influxdb_client::Record,
+            ) -> ::std::result::Result<Self, ::influxdb_client::RecordError> {
+                ::std::result::Result::Ok(#name {
+                    #(#initializers),*
+                })
             }
         }
     })
     .into()
 }
+
+/// Get the name given in `#[point(tag = "name")]` or `#[point(field = "name")]`,
+/// falling back to the name of the struct field.
+fn attribute_name(field: &syn::Field, namespace: &syn::Path) -> Result<String, TokenStream> {
+    if let syn::NestedMeta::Meta(syn::Meta::NameValue(syn::MetaNameValue { lit, .. })) =
+        namespace_parameter(&field.attrs, namespace).unwrap()
+    {
+        if let syn::Lit::Str(lit_str) = lit {
+            Ok(lit_str.value())
+        } else {
+            let span = lit.span();
+            Err(
+                (quote_spanned! { span => compile_error!("Attribute must be a string type"); })
+                    .into(),
+            )
+        }
+    } else {
+        Ok(field.ident.as_ref().unwrap().to_string())
+    }
+}
+
+/// Whether the type is written as `Option<T>`.
+fn is_option(ty: &syn::Type) -> bool {
+    if let syn::Type::Path(syn::TypePath { path, .. }) = ty {
+        path.segments
+            .last()
+            .is_some_and(|segment| segment.ident == "Option")
+    } else {
+        false
+    }
+}